@@ -1,4 +1,20 @@
-use crate::{decoded_len, encoded_len, Config};
+use crate::{base_58::MACRO_SCRATCHES, decoded_len, encoded_len_of, Config, Encoding};
+
+// The macros only compute lengths at compile-time,
+// so they compute the exact length of longer Base58 strings than the runtime functions.
+const fn macro_encoded_len(input: &[u8], cfg: Config) -> usize {
+    match cfg.encoding {
+        Encoding::Base58(_) => crate::base_58::encoded_len_of(input, cfg, MACRO_SCRATCHES),
+        _ => encoded_len_of(input, cfg),
+    }
+}
+
+const fn macro_decoded_len(input: &[u8], cfg: Config) -> usize {
+    match cfg.encoding {
+        Encoding::Base58(cset) => crate::base_58::decoded_len(input, cfg, cset, MACRO_SCRATCHES),
+        _ => decoded_len(input, cfg),
+    }
+}
 
 macro_rules! pass_types {
     ($length_fn:expr, $ty:ident) => {
@@ -51,10 +67,5 @@ pub struct CodecArgs {
     pub out_len: usize,
 }
 
-pass_types! {decoded_len, DecodeArgsFrom}
-pass_types! {_encoded_len, EncodeArgsFrom}
-
-#[inline(always)]
-const fn _encoded_len(bytes: &[u8], cfg: Config) -> usize {
-    encoded_len(bytes.len(), cfg)
-}
+pass_types! {macro_decoded_len, DecodeArgsFrom}
+pass_types! {macro_encoded_len, EncodeArgsFrom}
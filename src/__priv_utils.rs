@@ -9,6 +9,11 @@ pub(crate) const fn div_ceil_u64(l: u64, r: u64) -> u64 {
     l / r + added
 }
 
+pub(crate) const fn div_ceil_u128(l: u128, r: u128) -> u128 {
+    let added = if l % r == 0 { 0 } else { 1 };
+    l / r + added
+}
+
 pub(crate) const fn round_up_to_multiple_usize(l: usize, r: usize) -> usize {
     let rem = l % r;
     if rem == 0 {
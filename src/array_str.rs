@@ -71,6 +71,7 @@ mod arr {
 
 impl<const N: usize> ArrayStr<N> {
     /// Gets the length of this array string.
+    #[allow(clippy::len_without_is_empty)]
     pub const fn len(&self) -> usize {
         N
     }
@@ -1,110 +1,231 @@
-use crate::{encoding::INVALID_ENC, ArrayStr, Config, DecodeError, HexCharSet, WrongOutputLength};
-
-const UPPER_A_SUB_10: u8 = b'A' - 10;
-const LOWER_A_SUB_10: u8 = b'a' - 10;
-
-#[inline(always)]
-const fn hex_to_digit(hex: u8) -> u8 {
-    match hex {
-        b'0'..=b'9' => hex - b'0',
-        b'A'..=b'F' => hex - UPPER_A_SUB_10,
-        b'a'..=b'f' => hex - LOWER_A_SUB_10,
-        _ => crate::encoding::INVALID_ENC,
-    }
-}
-
-pub(crate) const fn encoded_len(input_len: usize, _config: Config) -> usize {
-    input_len * 2
+use crate::{
+    encode_decode_shared::{
+        decode_bits, decoded_len_bases, is_wrong_output_len, laid_out_len, lay_out,
+    },
+    encoding::{INVALID_ENC, SKIP_ENC},
+    Config, DecodeError, HexCharSet, InvalidByte, WrongInputLength, WrongOutputLength,
+};
+
+// Hexadecimal encodes 4 bits per byte
+const HEX_BITS_PER_BYTE: u64 = 4;
+
+pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
+    let per_byte = config.hex_byte_prefix.len() + 2;
+    let separators = input_len.saturating_sub(1) * config.hex_byte_separator.len();
+
+    config.hex_prefix.len() + laid_out_len(input_len * per_byte + separators, config)
 }
 
-pub(crate) const fn encode<const OUT: usize>(
+pub(crate) const fn encode(
     mut input: &[u8],
+    out: &mut [u8],
     config: Config,
     char_set: HexCharSet,
-) -> Result<ArrayStr<OUT>, WrongOutputLength> {
+) -> Result<usize, WrongOutputLength> {
     let output_len = encoded_len(input.len(), config);
 
-    if OUT != output_len {
+    if output_len > out.len() {
         return Err(crate::WrongOutputLength {
-            expected: OUT,
-            found: output_len,
+            expected: output_len,
+            found: out.len(),
         });
     }
 
-    let mut out = [0u8; OUT];
     let mut out_i = 0usize;
 
-    let digit_to_hex = match char_set {
-        HexCharSet::Lowercase => b"0123456789abcdef",
-        HexCharSet::Uppercase => b"0123456789ABCDEF",
-    };
+    let digit_to_hex = &char_set.lookup().into_enc;
+
+    macro_rules! write_str {
+        ($str:expr) => {
+            let bytes = $str.as_bytes();
+            for_range! {i in 0..bytes.len() =>
+                write_into! {out, out_i, bytes[i]}
+            }
+        };
+    }
+
+    write_str! {config.hex_prefix}
+    let body_start = out_i;
 
     while let [b, ref rem @ ..] = *input {
-        write_into! {out, out_i, digit_to_hex[(b >> 4) as usize]}
-        write_into! {out, out_i, digit_to_hex[(b & 0xF) as usize]}
+        if out_i != body_start {
+            write_str! {config.hex_byte_separator}
+        }
+        write_str! {config.hex_byte_prefix}
+
+        let (first, second) = if config.is_lsb_first() {
+            (b & 0xF, b >> 4)
+        } else {
+            (b >> 4, b & 0xF)
+        };
+        write_into! {out, out_i, digit_to_hex[first as usize]}
+        write_into! {out, out_i, digit_to_hex[second as usize]}
 
         input = rem;
     }
 
-    unsafe {
-        // SAFETY: out is only written bytes from `digit_to_hex`, which are all ascii.
-        Ok(ArrayStr::from_utf8_unchecked(out))
-    }
+    Ok(lay_out(out, body_start, out_i - body_start, 0, config))
 }
 
-pub(crate) const fn decoded_len(input: &[u8], _config: Config) -> usize {
-    input.len() / 2
+pub(crate) const fn decoded_len(input: &[u8], config: Config, char_set: HexCharSet) -> usize {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    if config.has_hex_format() {
+        parse_formatted(input, &mut [], config, from_enc).len
+    } else {
+        decoded_len_bases(input, config, from_enc, HEX_BITS_PER_BYTE)
+    }
 }
 
-pub(crate) const fn decode<const OUT: usize>(
-    mut input: &[u8],
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
     config: Config,
-) -> Result<[u8; OUT], DecodeError> {
-    let output_len = decoded_len(input, config);
+    char_set: HexCharSet,
+) -> Result<usize, DecodeError> {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    if !config.has_hex_format() {
+        return decode_bits(
+            input,
+            out,
+            exact_len,
+            config,
+            from_enc,
+            HEX_BITS_PER_BYTE as u32,
+        );
+    }
 
-    if input.len() % 2 == 1 {
-        return Err(DecodeError::WrongInputLength(crate::WrongInputLength {
-            length: input.len(),
-            enc: config.encoding,
-        }));
-    } else if output_len != OUT {
-        return Err(DecodeError::WrongOutputLength(WrongOutputLength {
-            expected: output_len,
-            found: OUT,
-        }));
+    let out_len = out.len();
+    let parsed = parse_formatted(input, out, config, from_enc);
+
+    if let Some(err) = parsed.error {
+        Err(err)
+    } else if is_wrong_output_len(parsed.len, out_len, exact_len) {
+        Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: parsed.len,
+            found: out_len,
+        }))
+    } else {
+        Ok(parsed.len)
     }
+}
 
-    let mut out = [0u8; OUT];
-    let mut out_i = 0usize;
-    let mut in_i = 0usize;
-
-    while let [oa, ob, ref rem @ ..] = *input {
-        let a = hex_to_digit(oa);
-        let b = hex_to_digit(ob);
-        if a == INVALID_ENC || b == INVALID_ENC {
-            let (index, byte) = if a == INVALID_ENC {
-                (in_i, oa)
-            } else {
-                (in_i + 1, ob)
-            };
-
-            return Err(DecodeError::InvalidByte(crate::InvalidByte {
-                index,
-                byte,
-                as_char: byte as char,
-                encoding: config.encoding,
-            }));
+struct Parsed {
+    // the amount of decoded bytes, which can be larger than the output slice
+    len: usize,
+    error: Option<DecodeError>,
+}
+
+// Decodes a hexadecimal string with the prefixes and separators from `config`
+// into the start of `out` (stopping writing once it's full),
+// skipping the bytes that `from_enc` skips (unless they're expected in that position).
+const fn parse_formatted(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+    from_enc: &[u8; 256],
+) -> Parsed {
+    let prefix = config.hex_prefix.as_bytes();
+    let separator = config.hex_byte_separator.as_bytes();
+    let byte_prefix = config.hex_byte_prefix.as_bytes();
+
+    let mut len = 0usize;
+
+    // how many bytes of the string prefix were matched
+    let mut prefix_i = 0usize;
+    // the position in the format of the current byte,
+    // which is the separator (if it's not the first byte), the byte prefix, and 2 digits.
+    let mut pos = 0usize;
+    let mut first_digit = 0u8;
+
+    macro_rules! error {
+        ($err:expr) => {
+            return Parsed {
+                len,
+                error: Some($err),
+            }
+        };
+    }
+
+    let mut in_i = 0;
+    while in_i < input.len() {
+        let byte = input[in_i];
+
+        let sep_len = if len == 0 { 0 } else { separator.len() };
+        let literals_len = sep_len + byte_prefix.len();
+
+        let expected = if prefix_i < prefix.len() {
+            Some(prefix[prefix_i])
+        } else if pos < sep_len {
+            Some(separator[pos])
+        } else if pos < literals_len {
+            Some(byte_prefix[pos - sep_len])
+        } else {
+            None
+        };
+
+        match expected {
+            Some(c) if eq_literal(byte, c, config) => {
+                if prefix_i < prefix.len() {
+                    prefix_i += 1;
+                } else {
+                    pos += 1;
+                }
+            }
+            _ if from_enc[byte as usize] == SKIP_ENC => {}
+            Some(_) => error! {invalid_byte(input, in_i, config)},
+            None => {
+                let digit = from_enc[byte as usize];
+                if digit == INVALID_ENC {
+                    error! {invalid_byte(input, in_i, config)}
+                } else if pos == literals_len {
+                    first_digit = digit;
+                    pos += 1;
+                } else {
+                    let value = if config.is_lsb_first() {
+                        first_digit | digit << 4
+                    } else {
+                        first_digit << 4 | digit
+                    };
+                    if len < out.len() {
+                        out[len] = value;
+                    }
+                    len += 1;
+                    pos = 0;
+                }
+            }
         }
 
-        write_into! {out, out_i, (a << 4) | b}
+        in_i += 1;
+    }
 
-        input = rem;
-        in_i += 2;
+    if prefix_i != prefix.len() || pos != 0 {
+        error! {DecodeError::WrongInputLength(WrongInputLength {
+            length: input.len(),
+            enc: config.encoding,
+        })}
     }
 
-    if input.len() == 1 {
-        panic!("BUG: `input` can't be length 1 here")
+    Parsed { len, error: None }
+}
+
+// Whether `byte` matches the `expected` byte of a prefix or separator,
+// which ignores ascii case unless hexadecimal decoding is case-strict.
+const fn eq_literal(byte: u8, expected: u8, config: Config) -> bool {
+    if config.has_strict_case() {
+        byte == expected
+    } else {
+        byte.eq_ignore_ascii_case(&expected)
     }
+}
 
-    Ok(out)
+const fn invalid_byte(input: &[u8], index: usize, config: Config) -> DecodeError {
+    DecodeError::InvalidByte(InvalidByte {
+        index,
+        byte: input[index],
+        as_char: input[index] as char,
+        encoding: config.encoding,
+    })
 }
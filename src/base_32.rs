@@ -1,6 +1,10 @@
 use crate::{
-    encode_decode_shared::{decoded_len_bases, encoded_len_bases},
-    B32CharSet, Config, DecodeError, Encoding, WrongOutputLength,
+    encode_decode_shared::{
+        decode_bits, decoded_len_bases, encoded_len_bases, laid_out_len, strip_checked_padding,
+    },
+    encoding::{INVALID_ENC, SKIP_ENC},
+    errors::WrongCheckSymbol,
+    B32CharSet, Config, DecodeError, InvalidByte, WrongInputLength, WrongOutputLength,
 };
 
 const MASK_5BITS: u8 = 0b11111;
@@ -12,8 +16,20 @@ const B32_CHUNK: usize = 8;
 // Base32 encodes 5 bits per byte
 const B32_BITS_PER_BYTE: u64 = 5;
 
+// The characters used for the Crockford check symbol,
+// after the 32 characters of the Crockford character set.
+const CROCKFORD_CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+const CROCKFORD_CHECK_MODULO: u32 = 37;
+
 pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
+    laid_out_len(unwrapped_len(input_len, config), config)
+}
+
+// The length of the encoded string before it's wrapped into lines
+const fn unwrapped_len(input_len: usize, config: Config) -> usize {
     encoded_len_bases(input_len, config, B32_BITS_PER_BYTE, B32_CHUNK)
+        + config.has_check_symbol() as usize
 }
 
 macro_rules! cast_shl {
@@ -25,35 +41,27 @@ macro_rules! cast_shl {
         ))|*
     )
 }
-macro_rules! cast_shr {
-    (
-        $($ident:ident $(>> $shift:expr)?),*
-    ) => (
-        $((
-            ($ident as u64) $(>> $shift)?
-        ))|*
-    )
-}
 
-pub(crate) const fn encode<const OUT: usize>(
+pub(crate) const fn encode(
     mut input: &[u8],
+    out: &mut [u8],
     config: Config,
     char_set: B32CharSet,
-) -> Result<crate::ArrayStr<OUT>, WrongOutputLength> {
+) -> Result<usize, WrongOutputLength> {
     crate::encode_decode_shared::encode_bases! {
-        input, config, char_set,
+        input, out, config, char_set, bits_per_char = B32_BITS_PER_BYTE as u32,
         {
             while let [a, b, c, d, e, ref rem @ ..] = *input {
                 let buffer = cast_shl!(a << 32, b << 24, c << 16, d << 8, e);
 
-                write_out!{ (buffer >> 35) as u64 }
-                write_out!{ (buffer >> 30) as u64 & MASK_5BITS64 }
-                write_out!{ (buffer >> 25) as u64 & MASK_5BITS64 }
-                write_out!{ (buffer >> 20) as u64 & MASK_5BITS64 }
-                write_out!{ (buffer >> 15) as u64 & MASK_5BITS64 }
-                write_out!{ (buffer >> 10) as u64 & MASK_5BITS64 }
-                write_out!{ (buffer >> 5 ) as u64 & MASK_5BITS64 }
-                write_out!{ buffer as u64 & MASK_5BITS64 }
+                write_out!{ buffer >> 35 }
+                write_out!{ (buffer >> 30) & MASK_5BITS64 }
+                write_out!{ (buffer >> 25) & MASK_5BITS64 }
+                write_out!{ (buffer >> 20) & MASK_5BITS64 }
+                write_out!{ (buffer >> 15) & MASK_5BITS64 }
+                write_out!{ (buffer >> 10) & MASK_5BITS64 }
+                write_out!{ (buffer >> 5 ) & MASK_5BITS64 }
+                write_out!{ buffer & MASK_5BITS64 }
 
                 input = rem;
             }
@@ -62,8 +70,8 @@ pub(crate) const fn encode<const OUT: usize>(
                 [a] => {
                     let buffer = a;
 
-                    write_out!{ (buffer >> 3) as u8 }
-                    write_out!{ (buffer << 2) as u8 & MASK_5BITS }
+                    write_out!{ buffer >> 3 }
+                    write_out!{ (buffer << 2) & MASK_5BITS }
                 }
                 [a, b] => {
                     let buffer = cast_shl!(a << 8, b);
@@ -95,113 +103,150 @@ pub(crate) const fn encode<const OUT: usize>(
                 }
                 _ => {}
             }
+        },
+        suffix = |encoded| if config.has_check_symbol() {
+            let checksum = crockford_checksum(encoded, &char_set.lookup().from_enc);
+            Some(crockford_check_symbol(checksum))
+        } else {
+            None
         }
     }
 }
 
-pub(crate) const fn decoded_len(input: &[u8], config: Config) -> usize {
-    decoded_len_bases(input, config, B32_BITS_PER_BYTE)
+pub(crate) const fn decoded_len(input: &[u8], config: Config, char_set: B32CharSet) -> usize {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    if config.has_check_symbol() {
+        match split_check_symbol(input, from_enc) {
+            Some((data, _)) => decoded_len_bases(data, config, from_enc, B32_BITS_PER_BYTE),
+            None => 0,
+        }
+    } else {
+        decoded_len_bases(input, config, from_enc, B32_BITS_PER_BYTE)
+    }
 }
 
-pub(crate) const fn decode<const OUT: usize>(
-    mut input: &[u8],
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
     config: Config,
     char_set: B32CharSet,
-) -> Result<[u8; OUT], DecodeError> {
-    crate::encode_decode_shared::decode_bases! {
-        dollar = $,
-        Encoding::Base32,
-        input, config, char_set,
-        matches!(input.len() % 8, 1 | 3 | 6),
-        |in_i| {
-            while let [oa, ob, oc, od, oe, of, og, oh, ref rem @ ..] = *input {
-                from_encoded! {
-                    a = oa, b = ob, c = oc, d = od,
-                    e = oe, f = of, g = og, h = oh
-                }
-
-                let buffer =
-                    cast_shl!(a << 35, b << 30, c << 25, d << 20, e << 15, f << 10, g << 5, h);
-
-                write_out!((buffer >> 32) as u8);
-                write_out!((buffer >> 24) as u8);
-                write_out!((buffer >> 16) as u8);
-                write_out!((buffer >> 8) as u8);
-                write_out!(buffer as u8);
-
-                input = rem;
-                in_i += 4;
-            }
-
-            let res_excess_bits = match *input {
-                [oa, ob, oc, od, oe, of, og] => {
-                    from_encoded! {a = oa, b = ob, c = oc, d = od, e = oe, f = of, g = og}
-
-                    let buffer =
-                        cast_shl!(a << 27, b << 22, c << 17, d << 12, e << 7, f << 2) |
-                        cast_shr!(g >> 3);
-
-                    write_out!((buffer >> 24) as u8);
-                    write_out!((buffer >> 16) as u8);
-                    write_out!((buffer >> 8) as u8);
-                    write_out!(buffer as u8);
-
-                    crate::encoding::CheckExcessBits {
-                        last_byte: og,
-                        decoded_byte: g,
-                        excess_bits: 3,
-                    }.call()
-                }
-                [oa, ob, oc, od, oe] => {
-                    from_encoded! {a = oa, b = ob, c = oc, d = od, e = oe}
-
-                    let buffer = cast_shl!(a << 19, b << 14, c << 9, d << 4) | cast_shr!(e >> 1);
+) -> Result<usize, DecodeError> {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+    let input = match strip_checked_padding(input, config, from_enc, B32_CHUNK) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if !config.has_check_symbol() {
+        return decode_bits(
+            input,
+            out,
+            exact_len,
+            config,
+            from_enc,
+            B32_BITS_PER_BYTE as u32,
+        );
+    }
 
-                    write_out!((buffer >> 16) as u8);
-                    write_out!((buffer >> 8) as u8);
-                    write_out!(buffer as u8);
+    let (data, check_byte) = match split_check_symbol(input, from_enc) {
+        Some(x) => x,
+        None => {
+            return Err(DecodeError::WrongInputLength(WrongInputLength {
+                length: 0,
+                enc: config.encoding,
+            }))
+        }
+    };
+
+    let len = match decode_bits(
+        data,
+        out,
+        exact_len,
+        config,
+        from_enc,
+        B32_BITS_PER_BYTE as u32,
+    ) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let found = crockford_check_value(check_byte, from_enc);
+
+    if found == INVALID_ENC {
+        return Err(DecodeError::InvalidByte(InvalidByte {
+            index: data.len(),
+            byte: check_byte,
+            as_char: check_byte as char,
+            encoding: config.encoding,
+        }));
+    }
 
-                    crate::encoding::CheckExcessBits {
-                        last_byte: oe,
-                        decoded_byte: e,
-                        excess_bits: 1,
-                    }.call()
-                }
-                [oa, ob, oc, od] => {
-                    from_encoded! {a = oa, b = ob, c = oc, d = od}
+    let expected = crockford_checksum(data, from_enc);
 
-                    let buffer = cast_shl!(a << 11, b << 6, c << 1) | cast_shr!(d >> 4);
+    if found != expected {
+        return Err(DecodeError::WrongCheckSymbol(WrongCheckSymbol {
+            index: data.len(),
+            expected: CROCKFORD_CHECK_SYMBOLS[expected as usize],
+            found: check_byte,
+        }));
+    }
 
-                    write_out!((buffer >> 8) as u8);
-                    write_out!(buffer as u8);
+    Ok(len)
+}
 
-                    crate::encoding::CheckExcessBits {
-                        last_byte: od,
-                        decoded_byte: d,
-                        excess_bits: 4,
-                    }.call()
-                }
-                [oa, ob] => {
-                    from_encoded! {a = oa, b = ob}
+// Splits the check symbol from the end of `input`, skipping trailing hyphens.
+const fn split_check_symbol<'a>(
+    mut input: &'a [u8],
+    from_enc: &[u8; 256],
+) -> Option<(&'a [u8], u8)> {
+    while let [ref rem @ .., last] = *input {
+        if from_enc[last as usize] != SKIP_ENC {
+            return Some((rem, last));
+        }
+        input = rem;
+    }
+    None
+}
 
-                    let buffer = cast_shl!(a << 3) | cast_shr!(b >> 2);
+// The value of the number encoded in `encoded` modulo 37,
+// ignoring bytes that don't encode a digit.
+const fn crockford_checksum(encoded: &[u8], from_enc: &[u8; 256]) -> u8 {
+    continue_crockford_checksum(0, encoded, from_enc)
+}
 
-                    write_out!(buffer as u8);
+// The Crockford checksum of the digits before `encoded` (which was `checksum`)
+// followed by the digits in `encoded`.
+pub(crate) const fn continue_crockford_checksum(
+    checksum: u8,
+    encoded: &[u8],
+    from_enc: &[u8; 256],
+) -> u8 {
+    let mut checksum = checksum as u32;
+
+    for_range! {i in 0..encoded.len() =>
+        let digit = from_enc[encoded[i] as usize];
+        if digit < 32 {
+            checksum = (checksum * 32 + digit as u32) % CROCKFORD_CHECK_MODULO;
+        }
+    }
 
-                    crate::encoding::CheckExcessBits {
-                        last_byte: ob,
-                        decoded_byte: b,
-                        excess_bits: 2,
-                    }.call()
-                }
-                [] => Ok(()),
-                _ => panic!("BUG: `input` can't be an invalid length here."),
-            };
+    checksum as u8
+}
 
-            if let Err(e) = res_excess_bits {
-                return Err(e);
-            }
+pub(crate) const fn crockford_check_symbol(checksum: u8) -> u8 {
+    CROCKFORD_CHECK_SYMBOLS[checksum as usize]
+}
 
-        }
+const fn crockford_check_value(byte: u8, from_enc: &[u8; 256]) -> u8 {
+    match byte {
+        b'*' => 32,
+        b'~' => 33,
+        b'$' => 34,
+        b'=' => 35,
+        b'U' | b'u' => 36,
+        _ if from_enc[byte as usize] < 32 => from_enc[byte as usize],
+        _ => INVALID_ENC,
     }
 }
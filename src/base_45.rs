@@ -0,0 +1,155 @@
+use crate::{
+    encode_decode_shared::{is_wrong_output_len, laid_out_len, lay_out, significant_len},
+    encoding::{CharSetLookup, INVALID_ENC, SKIP_ENC},
+    errors::ChunkOverflow,
+    Config, DecodeError, InvalidByte, WrongInputLength, WrongOutputLength,
+};
+
+const BASE: u32 = 45;
+
+// Every 2 bytes from the input is converted to 3 base45 encoded bytes
+const B45_CHUNK: usize = 3;
+const BYTES_CHUNK: usize = 2;
+
+pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
+    let len = input_len / BYTES_CHUNK * B45_CHUNK + input_len % BYTES_CHUNK * 2;
+
+    laid_out_len(len, config)
+}
+
+pub(crate) const fn encode(
+    mut input: &[u8],
+    out: &mut [u8],
+    config: Config,
+) -> Result<usize, WrongOutputLength> {
+    let output_len = encoded_len(input.len(), config);
+
+    if output_len > out.len() {
+        return Err(WrongOutputLength {
+            expected: output_len,
+            found: out.len(),
+        });
+    }
+
+    let into_enc = &CharSetLookup::<45>::BASE45.into_enc;
+
+    let mut out_i = 0usize;
+
+    // the digits are written in little-endian order
+    macro_rules! write_digits {
+        ($value:expr, $digits:expr) => {
+            let mut value = $value;
+            for_range! {_ in 0..$digits =>
+                write_into! {out, out_i, into_enc[(value % BASE) as usize]}
+                value /= BASE;
+            }
+        };
+    }
+
+    while let [a, b, ref rem @ ..] = *input {
+        write_digits! {(a as u32) << 8 | b as u32, 3}
+        input = rem;
+    }
+
+    if let [a] = *input {
+        write_digits! {a as u32, 2}
+    }
+
+    Ok(lay_out(out, 0, out_i, 0, config))
+}
+
+pub(crate) const fn decoded_len(input: &[u8], config: Config) -> usize {
+    let from_enc = &CharSetLookup::<45>::BASE45.for_decoding(config).from_enc;
+    let chars = significant_len(input, from_enc);
+
+    chars / B45_CHUNK * BYTES_CHUNK + chars % B45_CHUNK / 2
+}
+
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
+    config: Config,
+) -> Result<usize, DecodeError> {
+    let from_enc = &CharSetLookup::<45>::BASE45.for_decoding(config).from_enc;
+
+    for_range! {i in 0..input.len() =>
+        let byte = input[i];
+        if from_enc[byte as usize] == INVALID_ENC {
+            return Err(DecodeError::InvalidByte(InvalidByte {
+                index: i,
+                byte,
+                as_char: byte as char,
+                encoding: config.encoding,
+            }));
+        }
+    }
+
+    let chars = significant_len(input, from_enc);
+
+    if chars % B45_CHUNK == 1 {
+        return Err(DecodeError::WrongInputLength(WrongInputLength {
+            length: chars,
+            enc: config.encoding,
+        }));
+    }
+
+    let output_len = decoded_len(input, config);
+    if is_wrong_output_len(output_len, out.len(), exact_len) {
+        return Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: output_len,
+            found: out.len(),
+        }));
+    }
+
+    let mut out_i = 0usize;
+
+    let mut in_i = 0;
+    while in_i < input.len() {
+        // the digits of the chunk, in little-endian order
+        let mut digits = [0u8; B45_CHUNK];
+        let mut chunk_len = 0;
+        let mut chunk_start = input.len();
+
+        while chunk_len < B45_CHUNK && in_i < input.len() {
+            let digit = from_enc[input[in_i] as usize];
+            if digit != SKIP_ENC {
+                if chunk_len == 0 {
+                    chunk_start = in_i;
+                }
+                write_into! {digits, chunk_len, digit}
+            }
+            in_i += 1;
+        }
+
+        let mut value = 0u32;
+        let mut digit_i = chunk_len;
+        while digit_i != 0 {
+            digit_i -= 1;
+            value = value * BASE + digits[digit_i] as u32;
+        }
+
+        // a 3 character chunk encodes 2 bytes, a 2 character chunk encodes 1 byte
+        let max = if chunk_len == B45_CHUNK {
+            u16::MAX as u32
+        } else {
+            u8::MAX as u32
+        };
+
+        if value > max {
+            return Err(DecodeError::ChunkOverflow(ChunkOverflow {
+                index: chunk_start,
+                encoding: config.encoding,
+            }));
+        }
+
+        if chunk_len == B45_CHUNK {
+            write_into! {out, out_i, (value >> 8) as u8}
+        }
+        if chunk_len != 0 {
+            write_into! {out, out_i, value as u8}
+        }
+    }
+
+    Ok(out_i)
+}
@@ -0,0 +1,448 @@
+use crate::{
+    encode_decode_shared::{is_wrong_output_len, laid_out_len, lay_out},
+    encoding::{INVALID_ENC, SKIP_ENC},
+    errors::ChecksumMismatch,
+    B58CharSet, Config, DecodeError, InvalidByte, WrongInputLength, WrongOutputLength,
+};
+
+const BASE: u32 = 58;
+
+// The length of the checksum appended by Base58Check
+const CHECKSUM_LEN: usize = 4;
+
+// The capacities of the buffers used to compute the exact length of
+// base 58 encoded/decoded strings, the smallest one that fits the converted digits is used.
+//
+// Lengths computed at runtime only use the first `RUNTIME_SCRATCHES` buffers,
+// so that they use at most 8 KiB of stack,
+// the lengths of inputs that convert into more digits than that
+// (about 6 KB of bytes) are computed from the amount of digits in them,
+// which is an upper bound.
+//
+// The `encode!` and `decode!` macros only compute lengths at compile-time,
+// so they use every buffer, converting into up to `1 << 20` digits (about 750 KB of bytes).
+const LEN_SCRATCHES: [usize; 5] = [1 << 9, 1 << 11, 1 << 13, 1 << 17, 1 << 20];
+
+pub(crate) const RUNTIME_SCRATCHES: usize = 3;
+pub(crate) const MACRO_SCRATCHES: usize = LEN_SCRATCHES.len();
+
+// Maps every byte to itself, for converting from base 256.
+const IDENTITY: &[u8; 256] = &{
+    let mut out = [0u8; 256];
+    for_range! {i in 0..256 =>
+        out[i] = i as u8;
+    }
+    out
+};
+
+// log(256) / log(58), scaled by `LOG_SCALE` and rounded up
+const LOG_58_OF_256: u128 = 1_365_658_237_309_762;
+// log(58) / log(256), scaled by `LOG_SCALE` and rounded up
+const LOG_256_OF_58: u128 = 732_247_624_390_947;
+const LOG_SCALE: u128 = 1_000_000_000_000_000;
+
+// The maximum amount of base 58 digits that `len` bytes encode into,
+// which is the amount that `len` `0xFF` bytes encode into.
+const fn max_encoded_digits(len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (len as u128 * LOG_58_OF_256 / LOG_SCALE) as usize + 1
+    }
+}
+
+// The maximum amount of bytes that `len` base 58 digits decode into.
+const fn max_decoded_bytes(len: usize) -> usize {
+    crate::__priv_utils::div_ceil_u128(len as u128 * LOG_256_OF_58, LOG_SCALE) as usize
+}
+
+// The maximum length of base 58 encoding `input_len` bytes,
+// which is the length of encoding `input_len` `0xFF` bytes.
+pub(crate) const fn encoded_len(mut input_len: usize, config: Config) -> usize {
+    if config.has_checksum() {
+        input_len += CHECKSUM_LEN;
+    }
+
+    laid_out_len(max_encoded_digits(input_len), config)
+}
+
+pub(crate) const fn encoded_len_of(input: &[u8], config: Config, scratches: usize) -> usize {
+    let checksum = checksum_for(input, config);
+    let (zeros, input, suffix) = split_zeros(input, checksum_slice(&checksum, config), 0);
+
+    let max_len = max_encoded_digits(input.len() + suffix.len());
+    let conv = convert_len(input, suffix, IDENTITY, 256, BASE, max_len, scratches);
+    let len = if conv.overflowed { max_len } else { conv.len };
+
+    laid_out_len(zeros + len, config)
+}
+
+pub(crate) const fn encode(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+    char_set: B58CharSet,
+) -> Result<usize, WrongOutputLength> {
+    let into_enc = &char_set.lookup().into_enc;
+
+    let checksum = checksum_for(input, config);
+    let (zeros, number, suffix) = split_zeros(input, checksum_slice(&checksum, config), 0);
+
+    let out_len = out.len();
+    if zeros > out_len {
+        return Err(wrong_output_length(input, out_len, config));
+    }
+
+    // the digits are converted into `out`, after the leading zeros, in little-endian order
+    let (zero_digits, digits) = out.split_at_mut(zeros);
+    let conv = convert_digits(number, suffix, IDENTITY, 256, BASE, 0, digits);
+
+    if conv.overflowed || laid_out_len(zeros + conv.len, config) > out_len {
+        return Err(wrong_output_length(input, out_len, config));
+    }
+
+    for_range! {i in 0..zeros =>
+        zero_digits[i] = into_enc[0];
+    }
+
+    reverse(digits.split_at_mut(conv.len).0);
+    for_range! {i in 0..conv.len =>
+        digits[i] = into_enc[digits[i] as usize];
+    }
+
+    Ok(lay_out(out, 0, zeros + conv.len, 0, config))
+}
+
+#[cold]
+const fn wrong_output_length(input: &[u8], out_len: usize, config: Config) -> WrongOutputLength {
+    WrongOutputLength {
+        expected: encoded_len_of(input, config, RUNTIME_SCRATCHES),
+        found: out_len,
+    }
+}
+
+pub(crate) const fn decoded_len(
+    input: &[u8],
+    config: Config,
+    char_set: B58CharSet,
+    scratches: usize,
+) -> usize {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    let (zeros, number) = split_zero_digits(input, from_enc);
+
+    let max_len = max_decoded_bytes(count_digits(number, from_enc));
+    let conv = convert_len(number, &[], from_enc, BASE, 256, max_len, scratches);
+    let len = if conv.overflowed {
+        zeros + max_len
+    } else {
+        zeros + conv.len
+    };
+
+    if config.has_checksum() {
+        len.saturating_sub(CHECKSUM_LEN)
+    } else {
+        len
+    }
+}
+
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
+    config: Config,
+    char_set: B58CharSet,
+) -> Result<usize, DecodeError> {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    for_range! {i in 0..input.len() =>
+        let byte = input[i];
+        if from_enc[byte as usize] == INVALID_ENC {
+            return Err(DecodeError::InvalidByte(InvalidByte {
+                index: i,
+                byte,
+                as_char: byte as char,
+                encoding: config.encoding,
+            }));
+        }
+    }
+
+    let (zeros, number) = split_zero_digits(input, from_enc);
+
+    let checksum_len = if config.has_checksum() {
+        CHECKSUM_LEN
+    } else {
+        0
+    };
+
+    // the checksum is stored separately, in `conv.low`,
+    // and the rest of the digits are converted into `out`, in little-endian order.
+    let conv = convert_digits(number, &[], from_enc, BASE, 256, checksum_len, out);
+    let decoded_len = zeros + conv.len;
+
+    if decoded_len < checksum_len {
+        return Err(DecodeError::WrongInputLength(WrongInputLength {
+            length: input.len(),
+            enc: config.encoding,
+        }));
+    } else if conv.overflowed
+        || is_wrong_output_len(decoded_len - checksum_len, out.len(), exact_len)
+    {
+        return Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: self::decoded_len(input, config, char_set, RUNTIME_SCRATCHES),
+            found: out.len(),
+        }));
+    }
+
+    let out_len = decoded_len - checksum_len;
+    let digits_len = conv.len.saturating_sub(checksum_len);
+
+    // the digits are stored in big-endian order, after the leading zeros
+    reverse(out.split_at_mut(digits_len).0);
+
+    let zeros_len = out_len - digits_len;
+    let mut out_i = out_len;
+    while out_i > zeros_len {
+        out_i -= 1;
+        out[out_i] = out[out_i - zeros_len];
+    }
+    for_range! {i in 0..zeros_len =>
+        out[i] = 0;
+    }
+
+    if checksum_len != 0 {
+        let expected = checksum(out.split_at(out_len).0);
+        let found = [conv.low[3], conv.low[2], conv.low[1], conv.low[0]];
+
+        if u32::from_be_bytes(expected) != u32::from_be_bytes(found) {
+            return Err(DecodeError::ChecksumMismatch(ChecksumMismatch {
+                expected,
+                found,
+            }));
+        }
+    }
+
+    Ok(out_len)
+}
+
+// The checksum appended to the input by Base58Check:
+// the first 4 bytes of the double SHA-256 hash of the input.
+const fn checksum(input: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = crate::sha256::double_sha256(input);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+const fn checksum_for(input: &[u8], config: Config) -> [u8; CHECKSUM_LEN] {
+    if config.has_checksum() {
+        checksum(input)
+    } else {
+        [0; CHECKSUM_LEN]
+    }
+}
+
+const fn checksum_slice(checksum: &[u8; CHECKSUM_LEN], config: Config) -> &[u8] {
+    if config.has_checksum() {
+        checksum
+    } else {
+        &[]
+    }
+}
+
+const fn reverse(digits: &mut [u8]) {
+    let len = digits.len();
+    for_range! {i in 0..len / 2 =>
+        let tmp = digits[i];
+        digits[i] = digits[len - 1 - i];
+        digits[len - 1 - i] = tmp;
+    }
+}
+
+// Splits the leading `zero` bytes from the concatenation of `input` and `suffix`,
+// returning how many there were, and the remainder of both slices.
+const fn split_zeros<'a>(
+    mut input: &'a [u8],
+    mut suffix: &'a [u8],
+    zero: u8,
+) -> (usize, &'a [u8], &'a [u8]) {
+    let mut count = 0;
+    while let [b, ref rem @ ..] = *input {
+        if b != zero {
+            return (count, input, suffix);
+        }
+        count += 1;
+        input = rem;
+    }
+    while let [b, ref rem @ ..] = *suffix {
+        if b != zero {
+            break;
+        }
+        count += 1;
+        suffix = rem;
+    }
+    (count, input, suffix)
+}
+
+// The amount of bytes in `input` that are base 58 digits.
+const fn count_digits(input: &[u8], from_enc: &[u8; 256]) -> usize {
+    let mut count = 0;
+    for_range! {i in 0..input.len() =>
+        if (from_enc[input[i] as usize] as u32) < BASE {
+            count += 1;
+        }
+    }
+    count
+}
+
+// Splits the leading digits that encode `0` from `input`,
+// returning how many there were, and the remainder of `input`.
+//
+// Skipped bytes between the leading zero digits are also removed.
+const fn split_zero_digits<'a>(mut input: &'a [u8], from_enc: &[u8; 256]) -> (usize, &'a [u8]) {
+    let mut count = 0;
+    while let [b, ref rem @ ..] = *input {
+        match from_enc[b as usize] {
+            0 => count += 1,
+            SKIP_ENC => {}
+            _ => break,
+        }
+        input = rem;
+    }
+    (count, input)
+}
+
+struct Converted {
+    // the lowest `low_len` converted digits, in little-endian order
+    low: [u8; CHECKSUM_LEN],
+    // the amount of converted digits, including those in `low`.
+    len: usize,
+    // whether the converted number has more digits than fit in `low` and `digits`
+    overflowed: bool,
+}
+
+// Converts the number like `convert_digits` does,
+// into the smallest of the first `scratches` scratch buffers that fits `max_len` digits,
+// only to count the converted digits.
+const fn convert_len(
+    input: &[u8],
+    suffix: &[u8],
+    to_digit: &[u8; 256],
+    from_base: u32,
+    to_base: u32,
+    max_len: usize,
+    scratches: usize,
+) -> Converted {
+    macro_rules! convert_with {
+        ($($index:literal)*) => {
+            $(
+                if max_len <= LEN_SCRATCHES[$index] || $index == scratches - 1 {
+                    return convert_len_with::<{ LEN_SCRATCHES[$index] }>(
+                        input, suffix, to_digit, from_base, to_base,
+                    );
+                }
+            )*
+        };
+    }
+
+    convert_with! {0 1 2 3 4}
+    unreachable!()
+}
+
+// Each scratch buffer size is its own function,
+// so that only the stack space of the chosen size is used.
+#[inline(never)]
+const fn convert_len_with<const LEN: usize>(
+    input: &[u8],
+    suffix: &[u8],
+    to_digit: &[u8; 256],
+    from_base: u32,
+    to_base: u32,
+) -> Converted {
+    convert_digits(
+        input,
+        suffix,
+        to_digit,
+        from_base,
+        to_base,
+        0,
+        &mut [0; LEN],
+    )
+}
+
+// Converts the big-endian number in the concatenation of `input` and `suffix`
+// from `from_base` to `to_base`,
+// mapping each byte to a digit with `to_digit`.
+//
+// The converted digits after the lowest `low_len` are written to `digits`,
+// in little-endian order.
+//
+// Bytes that aren't valid digits are skipped.
+const fn convert_digits(
+    input: &[u8],
+    suffix: &[u8],
+    to_digit: &[u8; 256],
+    from_base: u32,
+    to_base: u32,
+    low_len: usize,
+    digits: &mut [u8],
+) -> Converted {
+    let mut low = [0u8; CHECKSUM_LEN];
+    let mut len = 0usize;
+
+    macro_rules! get_digit {
+        ($index:expr) => {
+            if $index < low_len {
+                low[$index]
+            } else {
+                digits[$index - low_len]
+            }
+        };
+    }
+
+    macro_rules! set_digit {
+        ($index:expr, $value:expr) => {
+            if $index < low_len {
+                low[$index] = $value;
+            } else {
+                digits[$index - low_len] = $value;
+            }
+        };
+    }
+
+    for_range! {i in 0..input.len() + suffix.len() =>
+        let byte = if i < input.len() {
+            input[i]
+        } else {
+            suffix[i - input.len()]
+        };
+
+        let mut carry = to_digit[byte as usize] as u32;
+
+        if carry < from_base {
+            for_range! {j in 0..len =>
+                carry += get_digit!(j) as u32 * from_base;
+                set_digit!(j, (carry % to_base) as u8);
+                carry /= to_base;
+            }
+
+            while carry != 0 {
+                if len == low_len + digits.len() {
+                    return Converted {
+                        low,
+                        len,
+                        overflowed: true,
+                    };
+                }
+
+                set_digit!(len, (carry % to_base) as u8);
+                len += 1;
+                carry /= to_base;
+            }
+        }
+    }
+
+    Converted {
+        low,
+        len,
+        overflowed: false,
+    }
+}
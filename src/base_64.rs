@@ -1,6 +1,8 @@
 use crate::{
-    encode_decode_shared::{decoded_len_bases, encoded_len_bases},
-    B64CharSet, Config, DecodeError, Encoding, WrongOutputLength,
+    encode_decode_shared::{
+        decode_bits, decoded_len_bases, encoded_len_bases, laid_out_len, strip_checked_padding,
+    },
+    B64CharSet, Config, DecodeError, WrongOutputLength,
 };
 
 const MASK_6BITS: u8 = 0b111111;
@@ -8,17 +10,26 @@ const MASK_6BITS: u8 = 0b111111;
 // Every 3 bytes from the input is converted to 4 base64 encoded bytes
 const B64_CHUNK: usize = 4;
 
+// Base64 encodes 6 bits per byte
+const B64_BITS_PER_BYTE: u64 = 6;
+
 pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
-    encoded_len_bases(input_len, config, 6, B64_CHUNK)
+    laid_out_len(unwrapped_len(input_len, config), config)
+}
+
+// The length of the encoded string before it's wrapped into lines
+const fn unwrapped_len(input_len: usize, config: Config) -> usize {
+    encoded_len_bases(input_len, config, B64_BITS_PER_BYTE, B64_CHUNK)
 }
 
-pub(crate) const fn encode<const OUT: usize>(
+pub(crate) const fn encode(
     mut input: &[u8],
+    out: &mut [u8],
     config: Config,
     char_set: B64CharSet,
-) -> Result<crate::ArrayStr<OUT>, WrongOutputLength> {
+) -> Result<usize, WrongOutputLength> {
     crate::encode_decode_shared::encode_bases! {
-        input, config, char_set,
+        input, out, config, char_set, bits_per_char = B64_BITS_PER_BYTE as u32,
         {
             while let [a, b, c, ref rem @ ..] = *input {
                 write_out!(a >> 2);
@@ -44,60 +55,32 @@ pub(crate) const fn encode<const OUT: usize>(
     }
 }
 
-pub(crate) const fn decoded_len(input: &[u8], config: Config) -> usize {
-    decoded_len_bases(input, config, 6)
+pub(crate) const fn decoded_len(input: &[u8], config: Config, char_set: B64CharSet) -> usize {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+
+    decoded_len_bases(input, config, from_enc, B64_BITS_PER_BYTE)
 }
 
-pub(crate) const fn decode<const OUT: usize>(
-    mut input: &[u8],
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
     config: Config,
     char_set: B64CharSet,
-) -> Result<[u8; OUT], DecodeError> {
-    crate::encode_decode_shared::decode_bases! {
-        dollar = $,
-        Encoding::Base64,
-        input, config, char_set,
-        input.len() % 4 == 1,
-        |in_i| {
-            while let [oa, ob, oc, od, ref rem @ ..] = *input {
-                from_encoded! {a = oa, b = ob, c = oc, d = od}
-
-                write_out!(a << 2 | (b >> 4));
-                write_out!((b << 4) | (c >> 2));
-                write_out!((c << 6) | d);
-                input = rem;
-                in_i += 4;
-            }
+) -> Result<usize, DecodeError> {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
 
-            let excess_bits_res = match *input {
-                [oa, ob, oc] => {
-                    from_encoded! {a = oa, b = ob, c = oc}
-                    write_out!(a << 2 | (b >> 4));
-                    write_out!((b << 4) | (c >> 2));
+    let input = match strip_checked_padding(input, config, from_enc, B64_CHUNK) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
 
-                    crate::encoding::CheckExcessBits {
-                        last_byte: oc,
-                        decoded_byte: c,
-                        excess_bits: 2,
-                    }.call()
-                }
-                [oa, ob] => {
-                    from_encoded! {a = oa, b = ob}
-                    write_out!(a << 2 | (b >> 4));
-
-                    crate::encoding::CheckExcessBits {
-                        last_byte: ob,
-                        decoded_byte: b,
-                        excess_bits: 4,
-                    }.call()
-                }
-                [] => Ok(()),
-                _ => panic!("BUG: `input` can't be an invalid length here"),
-            };
-
-            if let Err(e) = excess_bits_res {
-                return Err(e);
-            }
-        }
-    }
+    decode_bits(
+        input,
+        out,
+        exact_len,
+        config,
+        from_enc,
+        B64_BITS_PER_BYTE as u32,
+    )
 }
@@ -0,0 +1,304 @@
+use crate::{
+    encode_decode_shared::{is_wrong_output_len, laid_out_len, lay_out, significant_len},
+    encoding::{INVALID_ENC, SKIP_ENC},
+    errors::ChunkOverflow,
+    B85CharSet, Config, DecodeError, EncodeError, InvalidByte, WrongInputLength, WrongOutputLength,
+};
+
+const BASE: u32 = 85;
+
+// Every 4 bytes from the input is converted to 5 base85 encoded bytes
+const B85_CHUNK: usize = 5;
+const BYTES_CHUNK: usize = 4;
+
+// Ascii85 encodes a chunk of 4 zero bytes as this character
+const ZERO_CHUNK: u8 = b'z';
+
+const START_DELIM: &[u8; 2] = b"<~";
+const END_DELIM: &[u8; 2] = b"~>";
+
+// The maximum length of base 85 encoding `input_len` bytes,
+// which is the length of encoding `input_len` non-zero bytes.
+pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
+    laid_out_len(max_chars(input_len), config) + delimiters_len(config)
+}
+
+pub(crate) const fn encoded_len_of(input: &[u8], config: Config, char_set: B85CharSet) -> usize {
+    let mut chars = max_chars(input.len());
+
+    if let B85CharSet::Ascii85 = char_set {
+        chars -= count_zero_chunks(input) * (B85_CHUNK - 1);
+    }
+
+    laid_out_len(chars, config) + delimiters_len(config)
+}
+
+// The amount of characters (excluding delimiters and separators)
+// in the base 85 encoding of `input_len` non-zero bytes.
+//
+// Z85 can't encode partial chunks,
+// the length of encoding them is computed as for the other character sets.
+const fn max_chars(input_len: usize) -> usize {
+    let rem = input_len % BYTES_CHUNK;
+
+    let partial = if rem == 0 { 0 } else { rem + 1 };
+
+    input_len / BYTES_CHUNK * B85_CHUNK + partial
+}
+
+const fn delimiters_len(config: Config) -> usize {
+    if config.has_delimiters() {
+        START_DELIM.len() + END_DELIM.len()
+    } else {
+        0
+    }
+}
+
+// Returns the errors that `encode` returns regardless of the length of the output.
+pub(crate) const fn check_encode_input(
+    input_len: usize,
+    config: Config,
+    char_set: B85CharSet,
+) -> Result<(), EncodeError> {
+    if matches!(char_set, B85CharSet::Z85) && input_len % BYTES_CHUNK != 0 {
+        Err(EncodeError::WrongInputLength(WrongInputLength {
+            length: input_len,
+            enc: config.encoding,
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) const fn encode(
+    mut input: &[u8],
+    out: &mut [u8],
+    config: Config,
+    char_set: B85CharSet,
+) -> Result<usize, EncodeError> {
+    if let Err(e) = check_encode_input(input.len(), config, char_set) {
+        return Err(e);
+    }
+
+    let output_len = encoded_len_of(input, config, char_set);
+
+    if output_len > out.len() {
+        return Err(EncodeError::WrongOutputLength(WrongOutputLength {
+            expected: output_len,
+            found: out.len(),
+        }));
+    }
+
+    let into_enc = &char_set.lookup().into_enc;
+    let compresses_zeros = matches!(char_set, B85CharSet::Ascii85);
+
+    let mut out_i = 0usize;
+
+    if config.has_delimiters() {
+        write_into! {out, out_i, START_DELIM[0]}
+        write_into! {out, out_i, START_DELIM[1]}
+    }
+
+    while !input.is_empty() {
+        let mut chunk = [0u8; BYTES_CHUNK];
+        let mut chunk_len = 0;
+        while chunk_len < BYTES_CHUNK {
+            if let [b, ref rem @ ..] = *input {
+                write_into! {chunk, chunk_len, b}
+                input = rem;
+            } else {
+                break;
+            }
+        }
+
+        let mut value = u32::from_be_bytes(chunk);
+
+        if compresses_zeros && value == 0 && chunk_len == BYTES_CHUNK {
+            write_into! {out, out_i, ZERO_CHUNK}
+            continue;
+        }
+
+        let mut digits = [0u8; B85_CHUNK];
+        let mut digit_i = B85_CHUNK;
+        while digit_i != 0 {
+            digit_i -= 1;
+            digits[digit_i] = (value % BASE) as u8;
+            value /= BASE;
+        }
+
+        // a partial chunk of `N` bytes is encoded as `N + 1` characters
+        for_range! {i in 0..chunk_len + 1 =>
+            write_into! {out, out_i, into_enc[digits[i] as usize]}
+        }
+    }
+
+    let chars_end = out_i;
+
+    if config.has_delimiters() {
+        write_into! {out, out_i, END_DELIM[0]}
+        write_into! {out, out_i, END_DELIM[1]}
+    }
+
+    let chars_start = if config.has_delimiters() {
+        START_DELIM.len()
+    } else {
+        0
+    };
+    Ok(lay_out(
+        out,
+        chars_start,
+        chars_end - chars_start,
+        out_i - chars_end,
+        config,
+    ))
+}
+
+pub(crate) const fn decoded_len(input: &[u8], config: Config, char_set: B85CharSet) -> usize {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+    let (_, input) = strip_delimiters(input, char_set);
+
+    let mut zero_chunks = 0usize;
+
+    for_range! {i in 0..input.len() =>
+        if input[i] == ZERO_CHUNK && matches!(char_set, B85CharSet::Ascii85) {
+            zero_chunks += 1;
+        }
+    }
+
+    let chars = significant_len(input, from_enc) - zero_chunks;
+    let rem = chars % B85_CHUNK;
+    let partial = if rem == 0 { 0 } else { rem - 1 };
+
+    (zero_chunks + chars / B85_CHUNK) * BYTES_CHUNK + partial
+}
+
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
+    config: Config,
+    char_set: B85CharSet,
+) -> Result<usize, DecodeError> {
+    let from_enc = &char_set.lookup().for_decoding(config).from_enc;
+    let compresses_zeros = matches!(char_set, B85CharSet::Ascii85);
+
+    let (offset, stripped) = strip_delimiters(input, char_set);
+
+    // the amount of characters in the current chunk
+    let mut chunk_len = 0;
+
+    for_range! {i in 0..stripped.len() =>
+        let byte = stripped[i];
+
+        // `z` is only valid at the start of a chunk
+        let is_zero_chunk = compresses_zeros && byte == ZERO_CHUNK && chunk_len == 0;
+
+        if !is_zero_chunk {
+            match from_enc[byte as usize] {
+                INVALID_ENC => {
+                    return Err(DecodeError::InvalidByte(InvalidByte {
+                        index: offset + i,
+                        byte,
+                        as_char: byte as char,
+                        encoding: config.encoding,
+                    }));
+                }
+                SKIP_ENC => {}
+                _ => chunk_len = (chunk_len + 1) % B85_CHUNK,
+            }
+        }
+    }
+
+    if chunk_len == 1 || chunk_len != 0 && matches!(char_set, B85CharSet::Z85) {
+        return Err(DecodeError::WrongInputLength(WrongInputLength {
+            length: input.len(),
+            enc: config.encoding,
+        }));
+    }
+
+    let output_len = decoded_len(input, config, char_set);
+    if is_wrong_output_len(output_len, out.len(), exact_len) {
+        return Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: output_len,
+            found: out.len(),
+        }));
+    }
+
+    let mut out_i = 0usize;
+
+    let mut in_i = 0;
+    while in_i < stripped.len() {
+        if from_enc[stripped[in_i] as usize] == SKIP_ENC {
+            in_i += 1;
+            continue;
+        } else if compresses_zeros && stripped[in_i] == ZERO_CHUNK {
+            for_range! {_ in 0..BYTES_CHUNK =>
+                write_into! {out, out_i, 0}
+            }
+            in_i += 1;
+            continue;
+        }
+
+        let chunk_start = in_i;
+        let mut chunk_len = 0;
+        // the amount of digits read from the input
+        let mut read = 0;
+        let mut value = 0u64;
+
+        // a partial chunk is padded with the largest digit
+        while chunk_len < B85_CHUNK {
+            while in_i < stripped.len() && from_enc[stripped[in_i] as usize] == SKIP_ENC {
+                in_i += 1;
+            }
+
+            let digit = if in_i < stripped.len() {
+                in_i += 1;
+                read += 1;
+                from_enc[stripped[in_i - 1] as usize]
+            } else {
+                (BASE - 1) as u8
+            };
+            value = value * BASE as u64 + digit as u64;
+            chunk_len += 1;
+        }
+
+        if value > u32::MAX as u64 {
+            return Err(DecodeError::ChunkOverflow(ChunkOverflow {
+                index: offset + chunk_start,
+                encoding: config.encoding,
+            }));
+        }
+
+        let bytes = (value as u32).to_be_bytes();
+        for_range! {i in 0..read - 1 =>
+            write_into! {out, out_i, bytes[i]}
+        }
+    }
+
+    Ok(out_i)
+}
+
+// The amount of 4-byte chunks of zeros in `input`
+const fn count_zero_chunks(mut input: &[u8]) -> usize {
+    let mut count = 0;
+
+    while let [a, b, c, d, ref rem @ ..] = *input {
+        if a == 0 && b == 0 && c == 0 && d == 0 {
+            count += 1;
+        }
+        input = rem;
+    }
+
+    count
+}
+
+// Removes the `<~` `~>` delimiters around Ascii85 encoded strings,
+// returning the offset of the string without the delimiters.
+const fn strip_delimiters(input: &[u8], char_set: B85CharSet) -> (usize, &[u8]) {
+    match *input {
+        [b'<', b'~', ref rem @ .., b'~', b'>'] if matches!(char_set, B85CharSet::Ascii85) => {
+            (START_DELIM.len(), rem)
+        }
+        _ => (0, input),
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    encode_decode_shared::{laid_out_len, lay_out, significant_len},
+    encoding::{INVALID_ENC, SKIP_ENC},
+    errors::ChecksumMismatch,
+    Bech32Variant, Config, DecodeError, EncodeError, InvalidByte, InvalidHrp, WrongInputLength,
+    WrongOutputLength,
+};
+
+// The maximum length of the human-readable part
+pub(crate) const MAX_HRP_LEN: usize = 83;
+
+// The maximum length of Bech32 strings, from BIP-173,
+// not counting line endings and group separators.
+const MAX_LEN: usize = 90;
+
+// The amount of characters in the checksum
+const CHECKSUM_LEN: usize = 6;
+
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+// Whether `byte` is allowed in the human-readable part
+pub(crate) const fn is_hrp_byte(byte: u8) -> bool {
+    matches!(byte, b'!'..=b'~')
+}
+
+// The amount of data characters (excluding the checksum) that `input_len` bytes encode into
+const fn data_len(input_len: usize) -> usize {
+    crate::__priv_utils::div_ceil_u64(input_len as u64 * 8, 5) as usize
+}
+
+pub(crate) const fn encoded_len(input_len: usize, config: Config) -> usize {
+    config.hrp.len() + 1 + laid_out_len(data_len(input_len) + CHECKSUM_LEN, config)
+}
+
+// Returns the errors that `encode` returns regardless of the length of the output.
+pub(crate) const fn check_encode_input(
+    input_len: usize,
+    config: Config,
+) -> Result<(), EncodeError> {
+    if config.hrp.is_empty() {
+        Err(EncodeError::InvalidHrp(InvalidHrp {
+            expected: config.hrp,
+            length: 0,
+        }))
+    } else if config.hrp.len() + 1 + data_len(input_len) + CHECKSUM_LEN > MAX_LEN {
+        Err(EncodeError::WrongInputLength(WrongInputLength {
+            length: input_len,
+            enc: config.encoding,
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) const fn encode(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+    variant: Bech32Variant,
+) -> Result<usize, EncodeError> {
+    if let Err(e) = check_encode_input(input.len(), config) {
+        return Err(e);
+    }
+
+    let output_len = encoded_len(input.len(), config);
+
+    if output_len > out.len() {
+        return Err(EncodeError::WrongOutputLength(WrongOutputLength {
+            expected: output_len,
+            found: out.len(),
+        }));
+    }
+
+    let into_enc = &variant.lookup().into_enc;
+    let hrp = config.hrp.as_bytes();
+
+    let mut out_i = 0usize;
+
+    let mut checksum = Checksum::new(hrp);
+
+    for_range! {i in 0..hrp.len() =>
+        write_into! {out, out_i, hrp[i].to_ascii_lowercase()}
+    }
+    write_into! {out, out_i, b'1'}
+
+    let mut buffer = 0u32;
+    let mut buffer_bits = 0u32;
+
+    for_range! {i in 0..input.len() =>
+        buffer = (buffer << 8) | input[i] as u32;
+        buffer_bits += 8;
+
+        while buffer_bits >= 5 {
+            buffer_bits -= 5;
+            let digit = (buffer >> buffer_bits) & 0b11111;
+            checksum = checksum.push(digit as u8);
+            write_into! {out, out_i, into_enc[digit as usize]}
+        }
+        buffer &= (1 << buffer_bits) - 1;
+    }
+
+    if buffer_bits != 0 {
+        let digit = (buffer << (5 - buffer_bits)) & 0b11111;
+        checksum = checksum.push(digit as u8);
+        write_into! {out, out_i, into_enc[digit as usize]}
+    }
+
+    let checksum = checksum.finish(variant);
+    for_range! {i in 0..CHECKSUM_LEN =>
+        let digit = (checksum >> (5 * (CHECKSUM_LEN - 1 - i))) & 0b11111;
+        write_into! {out, out_i, into_enc[digit as usize]}
+    }
+
+    // only the data part is grouped
+    let data_start = hrp.len() + 1;
+    Ok(lay_out(out, data_start, out_i - data_start, 0, config))
+}
+
+pub(crate) const fn decoded_len(input: &[u8], config: Config, variant: Bech32Variant) -> usize {
+    let from_enc = &variant.lookup().for_decoding(config).from_enc;
+
+    let data_len = match split_separator(input) {
+        Some((_, data)) => significant_len(data, from_enc),
+        None => 0,
+    };
+
+    if data_len < CHECKSUM_LEN {
+        0
+    } else {
+        (data_len - CHECKSUM_LEN) * 5 / 8
+    }
+}
+
+pub(crate) const fn decode(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
+    config: Config,
+    variant: Bech32Variant,
+) -> Result<usize, DecodeError> {
+    let from_enc = &variant.lookup().for_decoding(config).from_enc;
+
+    // the case of the first letter, every other letter must have the same case
+    let mut is_upper = None::<bool>;
+
+    for_range! {i in 0..input.len() =>
+        let byte = input[i];
+
+        if config.group_size != 0 && byte == config.group_separator {
+            // group separators are skipped in the data part
+        } else if !is_hrp_byte(byte) {
+            return Err(invalid_byte(input, i, config));
+        } else if byte.is_ascii_alphabetic() {
+            match is_upper {
+                Some(is_upper) if is_upper != byte.is_ascii_uppercase() => {
+                    return Err(invalid_byte(input, i, config));
+                }
+                Some(_) => {}
+                None => is_upper = Some(byte.is_ascii_uppercase()),
+            }
+        }
+    }
+
+    let (hrp, data) = match split_separator(input) {
+        Some((hrp, data)) if !hrp.is_empty() && hrp.len() <= MAX_HRP_LEN => (hrp, data),
+        Some((hrp, _)) => return Err(invalid_hrp(hrp.len(), config)),
+        None => return Err(invalid_hrp(0, config)),
+    };
+    let data_offset = hrp.len() + 1;
+
+    for_range! {i in 0..data.len() =>
+        if from_enc[data[i] as usize] == INVALID_ENC {
+            return Err(invalid_byte(input, data_offset + i, config));
+        }
+    }
+
+    let data_len = significant_len(data, from_enc);
+
+    if data_len < CHECKSUM_LEN || data_offset + data_len > MAX_LEN {
+        return Err(DecodeError::WrongInputLength(WrongInputLength {
+            length: input.len(),
+            enc: config.encoding,
+        }));
+    } else if !config.hrp.is_empty() && !eq_ignore_case(hrp, config.hrp.as_bytes()) {
+        return Err(invalid_hrp(hrp.len(), config));
+    }
+
+    let (data, found_checksum) = split_checksum(data, from_enc);
+
+    let mut checksum = Checksum::new(hrp);
+    for_range! {i in 0..data.len() =>
+        let digit = from_enc[data[i] as usize];
+        if digit != SKIP_ENC {
+            checksum = checksum.push(digit);
+        }
+    }
+    let expected = checksum.finish(variant);
+
+    let mut found = 0u32;
+    for_range! {i in 0..CHECKSUM_LEN =>
+        found = (found << 5) | from_enc[found_checksum[i] as usize] as u32;
+    }
+
+    if expected != found {
+        return Err(DecodeError::ChecksumMismatch(ChecksumMismatch {
+            expected: expected.to_be_bytes(),
+            found: found.to_be_bytes(),
+        }));
+    }
+
+    crate::encode_decode_shared::decode_bits(data, out, exact_len, config, from_enc, 5)
+}
+
+// Incrementally computes the checksum of a Bech32 string
+struct Checksum {
+    polymod: u32,
+}
+
+impl Checksum {
+    // Starts the checksum with the expanded (lowercased) human-readable part
+    const fn new(hrp: &[u8]) -> Self {
+        let mut this = Self { polymod: 1 };
+
+        for_range! {i in 0..hrp.len() =>
+            this = this.push(hrp[i].to_ascii_lowercase() >> 5);
+        }
+        this = this.push(0);
+        for_range! {i in 0..hrp.len() =>
+            this = this.push(hrp[i].to_ascii_lowercase() & 0b11111);
+        }
+
+        this
+    }
+
+    const fn push(self, value: u8) -> Self {
+        let top = self.polymod >> 25;
+        let mut polymod = ((self.polymod & 0x1ffffff) << 5) ^ value as u32;
+
+        for_range! {i in 0..5 =>
+            if (top >> i) & 1 == 1 {
+                polymod ^= GENERATOR[i];
+            }
+        }
+
+        Self { polymod }
+    }
+
+    // Returns the 30-bit checksum, 5 bits for each checksum character
+    const fn finish(mut self, variant: Bech32Variant) -> u32 {
+        for_range! {_ in 0..CHECKSUM_LEN =>
+            self = self.push(0);
+        }
+
+        let constant = match variant {
+            Bech32Variant::Bech32 => 1,
+            Bech32Variant::Bech32m => 0x2bc830a3,
+        };
+
+        self.polymod ^ constant
+    }
+}
+
+// Splits `input` at the last `1`,
+// into the human-readable part before it and the data after it.
+const fn split_separator(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut hrp = input;
+
+    while let [ref rem @ .., last] = *hrp {
+        hrp = rem;
+
+        if last == b'1' {
+            let mut data = input;
+            for_range! {_ in 0..hrp.len() + 1 =>
+                if let [_, ref rem @ ..] = *data {
+                    data = rem;
+                }
+            }
+            return Some((hrp, data));
+        }
+    }
+
+    None
+}
+
+// Splits the checksum from the end of `data`,
+// which must have at least 6 bytes that aren't skipped.
+const fn split_checksum<'a>(
+    mut data: &'a [u8],
+    from_enc: &[u8; 256],
+) -> (&'a [u8], [u8; CHECKSUM_LEN]) {
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    let mut found = 0;
+
+    while found < CHECKSUM_LEN {
+        if let [ref rem @ .., last] = *data {
+            if from_enc[last as usize] != SKIP_ENC {
+                found += 1;
+                checksum[CHECKSUM_LEN - found] = last;
+            }
+            data = rem;
+        } else {
+            break;
+        }
+    }
+
+    (data, checksum)
+}
+
+const fn eq_ignore_case(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    for_range! {i in 0..left.len() =>
+        if !left[i].eq_ignore_ascii_case(&right[i]) {
+            return false;
+        }
+    }
+
+    true
+}
+
+const fn invalid_byte(input: &[u8], index: usize, config: Config) -> DecodeError {
+    DecodeError::InvalidByte(InvalidByte {
+        index,
+        byte: input[index],
+        as_char: input[index] as char,
+        encoding: config.encoding,
+    })
+}
+
+const fn invalid_hrp(length: usize, config: Config) -> DecodeError {
+    DecodeError::InvalidHrp(InvalidHrp {
+        expected: config.hrp,
+        length,
+    })
+}
@@ -43,6 +43,16 @@
 /// # }
 /// ```
 ///
+/// ### Base 32 (extended hex alphabet)
+///
+/// ```rust
+/// use const_base::{decode, Config};
+///
+/// const OUT: &[u8] = decode!("CPNMUOJ1", Config::B32_HEX);
+///     
+/// assert_eq!(OUT, b"fooba");
+/// ```
+///
 /// <div id = "erroring-example"></div>
 ///
 ///
@@ -56,6 +66,16 @@
 /// assert_eq!(OUT, &[0xF0, 0x0B]);
 /// ```
 ///
+/// ### Bech32
+///
+/// ```rust
+/// use const_base::{decode, Config};
+///
+/// const OUT: &[u8] = decode!("hi1dpjkcmr0gsl7u9", Config::bech32m("hi"));
+///     
+/// assert_eq!(OUT, b"hello");
+/// ```
+///
 /// <div id = "erroring-example"></div>
 ///
 /// ### Erroring
@@ -144,14 +164,14 @@ macro_rules! decode {
 /// assert_eq!(OUT, "AMCQQ===");
 /// ```
 ///
-/// ### Base 32
+/// ### Base 32 (extended hex alphabet)
 ///
 /// ```rust
 /// use const_base::{encode, Config};
 ///
-/// const OUT: &str = encode!(&[3, 5, 8], Config::B32).as_str();
+/// const OUT: &str = encode!(&[3, 5, 8], Config::B32_HEX).as_str();
 ///     
-/// assert_eq!(OUT, "AMCQQ===");
+/// assert_eq!(OUT, "0C2GG===");
 /// ```
 ///
 /// ### Hexadecimal
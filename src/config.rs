@@ -1,82 +1,1188 @@
-use crate::{B32CharSet, B64CharSet, Encoding, HexCharSet};
+use crate::{
+    B32CharSet, B58CharSet, B64CharSet, B85CharSet, Bech32Variant, CustomCharSet, Encoding,
+    HexCharSet,
+};
 
 /// For configuring how a string is encoded/decoded.
 ///
 /// `Config` has these values by default:
 ///
 /// - `end_padding = true`
+/// - `check_symbol = false`
+/// - `strict_case = false`
+/// - `case_insensitive = false`
+/// - `allow_excess_bits = false`
+/// - `bit_order = BitOrder::MsbFirst`
+/// - `hex_prefix = ""`
+/// - `hex_byte_prefix = ""`
+/// - `hex_byte_separator = ""`
+/// - `checksum = false`
+/// - `hrp = ""`
+/// - `delimiters = false`
+/// - `padding_char = b'='`
+/// - no [`padding_mode`](Config::padding_mode)
+/// - `ignore_bytes = b""`
+/// - no [line wrapping](Config::line_wrap)
+/// - no [grouping](Config::group)
 ///
 #[derive(Debug, Copy, Clone)]
 pub struct Config {
     pub(crate) encoding: Encoding,
     pub(crate) end_padding: bool,
+    pub(crate) check_symbol: bool,
+    pub(crate) strict_case: bool,
+    pub(crate) case_insensitive: bool,
+    pub(crate) excess_bits: bool,
+    pub(crate) bit_order: BitOrder,
+    pub(crate) hex_prefix: &'static str,
+    pub(crate) hex_byte_prefix: &'static str,
+    pub(crate) hex_byte_separator: &'static str,
+    pub(crate) checksum: bool,
+    pub(crate) hrp: &'static str,
+    pub(crate) delimiters: bool,
+    pub(crate) padding: u8,
+    pub(crate) padding_mode: Option<PaddingMode>,
+    pub(crate) ignore: &'static [u8],
+    pub(crate) line_width: usize,
+    pub(crate) line_ending: LineEnding,
+    pub(crate) group_size: usize,
+    pub(crate) group_separator: u8,
+}
+
+/// Determines how the padding at the end of encoded strings is validated when decoding.
+///
+/// Only the [`Base64`](crate::Encoding::Base64) and
+/// [`Base32`](crate::Encoding::Base32) encodings use padding,
+/// other encodings ignore this setting.
+///
+/// Padding that isn't allowed by the mode causes an
+/// [`InvalidPadding`](crate::DecodeError::InvalidPadding) error.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PaddingMode {
+    /// Requires the exact padding that encoding with
+    /// [`end_padding(true)`](Config::end_padding) produces.
+    Required,
+    /// Requires the string to have no padding.
+    Forbidden,
+    /// Accepts any amount of padding, including none.
+    ///
+    /// This is how padding is decoded by default when
+    /// [`end_padding`](Config::end_padding) is `true`.
+    Indifferent,
+}
+
+/// Determines the order in which the bits of each byte are encoded.
+///
+/// Used by [`Config::bit_order`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Encodes the most significant bits of each byte first.
+    ///
+    /// This is the default bit order, used by RFC 4648.
+    MsbFirst,
+    /// Encodes the least significant bits of each byte first.
+    LsbFirst,
+}
+
+/// Determines which characters end each line of line-wrapped strings.
+///
+/// Used by [`Config::line_wrap`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Lines end with `\n`.
+    Lf,
+    /// Lines end with `\r\n`, as in MIME bodies.
+    CrLf,
+}
+
+impl LineEnding {
+    pub(crate) const fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::Lf => b"\n",
+            LineEnding::CrLf => b"\r\n",
+        }
+    }
+}
+
+#[track_caller]
+const fn assert_ascii_format(method: &str, str: &str) {
+    use const_panic::{FmtArg, PanicVal};
+
+    let bytes = str.as_bytes();
+    for_range! {i in 0..bytes.len() =>
+        if !bytes[i].is_ascii() {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the string passed to `Config::"),
+                PanicVal::write_str(method),
+                PanicVal::write_str("` must be ascii, found: "),
+                PanicVal::from_str(str, FmtArg::DEBUG),
+            ])
+        }
+    }
 }
 
 impl Config {
     /// Constructs a `Config` from an [Encoding]
     ///
-    /// You can use [the associated constants](#associated-consts) for a more
-    /// concise way to get a `Config`
+    /// You can use [the associated constants](#associated-consts) for a more
+    /// concise way to get a `Config`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{B64CharSet, Config, Encoding, encode};
+    ///
+    /// // The same as `Config::B64`
+    /// const CFG: Config = Config::new(Encoding::Base64(B64CharSet::Standard));
+    ///
+    /// assert_eq!(encode!("Rust", CFG), "UnVzdA==")
+    ///
+    /// ```
+    pub const fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            end_padding: true,
+            check_symbol: false,
+            strict_case: false,
+            case_insensitive: false,
+            excess_bits: false,
+            bit_order: BitOrder::MsbFirst,
+            hex_prefix: "",
+            hex_byte_prefix: "",
+            hex_byte_separator: "",
+            checksum: false,
+            hrp: "",
+            delimiters: false,
+            padding: b'=',
+            padding_mode: None,
+            ignore: b"",
+            line_width: 0,
+            line_ending: LineEnding::Lf,
+            group_size: 0,
+            group_separator: b' ',
+        }
+    }
+
+    /// Constructs a [`Base64`](crate::Encoding::Base64) `Config`
+    /// that uses `alphabet` as its [character set](crate::B64CharSet::Custom).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` contains non-ascii bytes, duplicate bytes,
+    /// or the `=` padding byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// // the character set used by crypt(3)
+    /// const CRYPT: Config =
+    ///     Config::custom_b64(b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+    ///
+    /// assert_eq!(encode!(b"hello", CRYPT), "O4JgP4w=");
+    ///
+    /// assert_eq!(decode!("O4JgP4w=", CRYPT), b"hello");
+    /// ```
+    #[track_caller]
+    pub const fn custom_b64(alphabet: &'static [u8; 64]) -> Self {
+        Self::new(Encoding::Base64(B64CharSet::Custom(CustomCharSet::new(
+            alphabet,
+        ))))
+    }
+
+    /// Constructs a [`Base32`](crate::Encoding::Base32) `Config`
+    /// that uses `alphabet` as its [character set](crate::B32CharSet::Custom).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` contains non-ascii bytes, duplicate bytes,
+    /// or the `=` padding byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::custom_b32(b"0123456789abcdefghjkmnpqrtuvwxyz");
+    ///
+    /// assert_eq!(encode!(b"hello", CFG), "d1jprv3f");
+    ///
+    /// assert_eq!(decode!("d1jprv3f", CFG), b"hello");
+    /// ```
+    #[track_caller]
+    pub const fn custom_b32(alphabet: &'static [u8; 32]) -> Self {
+        Self::new(Encoding::Base32(B32CharSet::Custom(CustomCharSet::new(
+            alphabet,
+        ))))
+    }
+
+    /// Constructs a [`Hex`](crate::Encoding::Hex) `Config`
+    /// that uses `alphabet` as its [character set](crate::HexCharSet::Custom).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` contains non-ascii bytes, duplicate bytes,
+    /// or the `=` padding byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::custom_hex(b"zyxwvutsrqponmlk");
+    ///
+    /// assert_eq!(encode!(b"hello", CFG), "trtutntntk");
+    ///
+    /// assert_eq!(decode!("trtutntntk", CFG), b"hello");
+    /// ```
+    #[track_caller]
+    pub const fn custom_hex(alphabet: &'static [u8; 16]) -> Self {
+        Self::new(Encoding::Hex(HexCharSet::Custom(CustomCharSet::new(
+            alphabet,
+        ))))
+    }
+
+    /// Constructs a [`Bech32`](crate::Encoding::Bech32) `Config`
+    /// that uses the [`Bech32`](crate::Bech32Variant::Bech32) variant from BIP-173,
+    /// with `hrp` as its [human-readable part](Self::hrp).
+    ///
+    /// To decode strings with any human-readable part, use
+    /// [`Config::new(Encoding::Bech32(Bech32Variant::Bech32))`](Self::new)
+    /// instead, which can't encode strings.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`hrp`](Self::hrp).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Bech32Variant, Config, Encoding, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"hello", Config::bech32("hi")), "hi1dpjkcmr0av0je8");
+    ///
+    /// const ANY_HRP: Config = Config::new(Encoding::Bech32(Bech32Variant::Bech32));
+    /// assert_eq!(decode!("hi1dpjkcmr0av0je8", ANY_HRP), b"hello");
+    /// ```
+    #[track_caller]
+    pub const fn bech32(hrp: &'static str) -> Self {
+        Self::new(Encoding::Bech32(Bech32Variant::Bech32)).hrp(hrp)
+    }
+
+    /// Constructs a [`Bech32`](crate::Encoding::Bech32) `Config`
+    /// that uses the [`Bech32m`](crate::Bech32Variant::Bech32m) variant from BIP-350,
+    /// with `hrp` as its [human-readable part](Self::hrp).
+    ///
+    /// To decode strings with any human-readable part, use
+    /// [`Config::new(Encoding::Bech32(Bech32Variant::Bech32m))`](Self::new)
+    /// instead, which can't encode strings.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same situations as [`hrp`](Self::hrp).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Bech32Variant, Config, Encoding, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"hello", Config::bech32m("hi")), "hi1dpjkcmr0gsl7u9");
+    ///
+    /// const ANY_HRP: Config = Config::new(Encoding::Bech32(Bech32Variant::Bech32m));
+    /// assert_eq!(decode!("hi1dpjkcmr0gsl7u9", ANY_HRP), b"hello");
+    /// ```
+    #[track_caller]
+    pub const fn bech32m(hrp: &'static str) -> Self {
+        Self::new(Encoding::Bech32(Bech32Variant::Bech32m)).hrp(hrp)
+    }
+
+    /// Determines whether the string has padding at the end.
+    /// This is `true` by default.
+    ///
+    /// For each encoding, the strings are padded to a multiple:
+    ///
+    /// - Base64: pads to be a multiple of 4 long, with `=`.
+    /// - Base32: pads to be a multiple of 8 long, with `=`
+    ///   (except for the [`Crockford`](crate::B32CharSet::Crockford) character set,
+    ///   which is never padded).
+    /// - Hexadecimal: requires no padding
+    /// - Base58: requires no padding
+    /// - Base45: requires no padding
+    /// - Base85: requires no padding
+    /// - Bech32: requires no padding
+    ///
+    /// The padding character can be changed with [`padding_char`](Self::padding_char).
+    ///
+    /// When decoding, padding is accepted if this is `true`
+    /// or a [`padding_mode`](Self::padding_mode) is set,
+    /// otherwise padding characters are invalid bytes.
+    ///
+    /// # Examples
+    ///
+    /// ### Base 64
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!("Rust", Config::B64), "UnVzdA==");
+    ///
+    /// assert_eq!(encode!("Rust", Config::B64.end_padding(true)), "UnVzdA==");
+    ///
+    /// assert_eq!(encode!("Rust", Config::B64.end_padding(false)), "UnVzdA");
+    ///
+    /// ```
+    ///
+    /// ### Base 32
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!("Rustic", Config::B32), "KJ2XG5DJMM======");
+    ///
+    /// assert_eq!(encode!("Rustic", Config::B32.end_padding(true)), "KJ2XG5DJMM======");
+    ///
+    /// assert_eq!(encode!("Rustic", Config::B32.end_padding(false)), "KJ2XG5DJMM");
+    ///
+    /// ```
+    pub const fn end_padding(mut self, have: bool) -> Self {
+        self.end_padding = have;
+        self
+    }
+
+    /// Sets the character that the string is padded with.
+    /// This is `b'='` by default.
+    ///
+    /// Only the [`Base64`](crate::Encoding::Base64) and
+    /// [`Base32`](crate::Encoding::Base32) encodings use padding,
+    /// other encodings ignore this setting.
+    ///
+    /// When decoding, only this character is stripped from the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `padding` isn't ascii,
+    /// if it's a character that the encoding decodes
+    /// (eg: `b'A'` for [`Config::B64`]),
+    /// or if it's one of the bytes skipped when decoding:
+    /// the [ignored bytes](Self::ignore_bytes), the [group separator](Self::group),
+    /// or the `\r` and `\n` bytes when [wrapping lines](Self::line_wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::B64_URL_SAFE.padding_char(b'.');
+    ///
+    /// assert_eq!(encode!(b"Rust", CFG), "UnVzdA..");
+    ///
+    /// assert_eq!(decode!("UnVzdA..", CFG), b"Rust");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn padding_char(mut self, padding: u8) -> Self {
+        use const_panic::{FmtArg, PanicVal};
+
+        if !padding.is_ascii() {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the padding character must be ascii, found: "),
+                PanicVal::from_u8(padding, FmtArg::DEBUG),
+            ])
+        }
+
+        self.padding = padding;
+        self.assert_skipped_bytes();
+        self
+    }
+
+    /// Determines how the padding at the end of the string is validated when decoding.
+    ///
+    /// When no mode is set, padding is decoded like [`PaddingMode::Indifferent`]
+    /// if [`end_padding`](Self::end_padding) is `true`,
+    /// and padding characters are invalid bytes if it's `false`.
+    /// Setting a mode makes padding be decoded regardless of `end_padding`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, PaddingMode};
+    ///
+    /// const LENIENT: Config = Config::B64;
+    /// const STRICT: Config = Config::B64.padding_mode(PaddingMode::Required);
+    ///
+    /// assert_eq!(LENIENT.decode::<1>(b"Zg"), Ok(*b"f"));
+    /// assert_eq!(LENIENT.decode::<1>(b"Zg="), Ok(*b"f"));
+    /// assert_eq!(LENIENT.decode::<1>(b"Zg======"), Ok(*b"f"));
+    ///
+    /// assert_eq!(STRICT.decode::<1>(b"Zg=="), Ok(*b"f"));
+    ///
+    /// match STRICT.decode::<1>(b"Zg=") {
+    ///     Err(DecodeError::InvalidPadding(err)) => {
+    ///         assert_eq!(err.expected(), 2);
+    ///         assert_eq!(err.found(), 1);
+    ///     }
+    ///     _ => unreachable!()
+    /// }
+    ///
+    /// ```
+    pub const fn padding_mode(mut self, mode: PaddingMode) -> Self {
+        self.padding_mode = Some(mode);
+        self
+    }
+
+    /// Sets the bytes that are skipped when decoding.
+    /// This is `b""` by default.
+    ///
+    /// Only the [`Base64`](crate::Encoding::Base64),
+    /// [`Base32`](crate::Encoding::Base32), and [`Hex`](crate::Encoding::Hex)
+    /// encodings support ignoring bytes, other encodings ignore this setting.
+    ///
+    /// Ignored bytes can appear anywhere in the decoded string, including between
+    /// and after the padding characters.
+    /// [`decoded_len`](crate::decoded_len) only counts the bytes that aren't ignored,
+    /// and [`InvalidByte::index`](crate::InvalidByte::index) is still the
+    /// offset in the unstripped string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bytes is part of the character set of the encoding,
+    /// or is the [padding character](Self::padding_char).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode};
+    ///
+    /// const CFG: Config = Config::B64.ignore_bytes(b" \t\r\n");
+    ///
+    /// const DECODED: &[u8] = decode!(concat!(
+    ///     "SGVsbG8s\n",
+    ///     "IHdvcmxk\r\n",
+    ///     "IQ==\n",
+    /// ), CFG);
+    ///
+    /// assert_eq!(DECODED, b"Hello, world!");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn ignore_bytes(mut self, bytes: &'static [u8]) -> Self {
+        self.ignore = bytes;
+        self.assert_skipped_bytes();
+        self
+    }
+
+    /// Wraps the encoded string into lines of `width` characters,
+    /// separated by `ending`.
+    ///
+    /// Only the [`Base64`](crate::Encoding::Base64),
+    /// [`Base32`](crate::Encoding::Base32), and [`Hex`](crate::Encoding::Hex)
+    /// encodings support line wrapping, other encodings ignore this setting.
+    ///
+    /// The last line can be shorter than `width`,
+    /// and isn't followed by a line ending.
+    /// [`encoded_len`](crate::encoded_len) includes the line endings.
+    ///
+    /// When decoding, the `\r` and `\n` characters are skipped anywhere in the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero,
+    /// or if the `\r` or `\n` bytes are part of the character set of the encoding,
+    /// or are the [padding character](Self::padding_char).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, LineEnding, decode, encode_as_str};
+    ///
+    /// // PEM bodies are wrapped at 64 columns, MIME bodies at 76 columns.
+    /// const CFG: Config = Config::B64.line_wrap(8, LineEnding::CrLf);
+    ///
+    /// const ENCODED: &str = encode_as_str!(b"Hello, world!", CFG);
+    /// assert_eq!(ENCODED, "SGVsbG8s\r\nIHdvcmxk\r\nIQ==");
+    ///
+    /// assert_eq!(decode!(ENCODED, CFG), b"Hello, world!");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn line_wrap(mut self, width: usize, ending: LineEnding) -> Self {
+        use const_panic::PanicVal;
+
+        if width == 0 {
+            crate::utils::cpanic(&[PanicVal::write_str("the line width must be nonzero")])
+        }
+
+        self.line_width = width;
+        self.line_ending = ending;
+        self.assert_skipped_bytes();
+        self
+    }
+
+    /// Splits the encoded string into groups of `size` characters,
+    /// separated by the `separator` byte.
+    ///
+    /// All encodings support grouping.
+    /// [`Bech32`](crate::Encoding::Bech32) only groups the data part after the `1`,
+    /// and [`Ascii85`](crate::B85CharSet::Ascii85) only groups the characters
+    /// between the [delimiters](Self::delimiters).
+    ///
+    /// The last group can be shorter than `size`,
+    /// and isn't followed by a separator.
+    /// [`encoded_len`](crate::encoded_len) includes the separators.
+    ///
+    /// When combined with [line wrapping](Self::line_wrap),
+    /// both the line width and the group size only count the encoded characters
+    /// from the start of the string,
+    /// and line endings replace the separators at the end of lines.
+    ///
+    /// When decoding, the separator is skipped anywhere in the string.
+    /// [`decoded_len`](crate::decoded_len) doesn't count the separators,
+    /// and [`InvalidByte::index`](crate::InvalidByte::index) is still the
+    /// offset in the unstripped string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero, if `separator` isn't ascii,
+    /// or if `separator` is part of the character set of the encoding,
+    /// or is the [padding character](Self::padding_char).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode_as_str};
+    ///
+    /// const CFG: Config = Config::HEX.group(2, b':');
+    ///
+    /// const ENCODED: &str = encode_as_str!(&[0xDE, 0xAD, 0xBE, 0xEF], CFG);
+    /// assert_eq!(ENCODED, "DE:AD:BE:EF");
+    ///
+    /// assert_eq!(decode!(ENCODED, CFG), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    ///
+    /// const KEY: &str = encode_as_str!(b"hello, world", Config::B32_CROCKFORD.group(4, b'-'));
+    /// assert_eq!(KEY, "D1JP-RV3F-5GG7-EVVJ-DHJ0");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn group(mut self, size: usize, separator: u8) -> Self {
+        use const_panic::{FmtArg, PanicVal};
+
+        if size == 0 {
+            crate::utils::cpanic(&[PanicVal::write_str("the group size must be nonzero")])
+        } else if !separator.is_ascii() {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the group separator must be ascii, found: "),
+                PanicVal::from_u8(separator, FmtArg::DEBUG),
+            ])
+        }
+
+        self.group_size = size;
+        self.group_separator = separator;
+        self.assert_skipped_bytes();
+        self
+    }
+
+    /// Determines whether decoding hexadecimal only accepts
+    /// the letters in the case of the character set.
+    /// This is `false` by default.
+    ///
+    /// Only the [`Hex`](crate::Encoding::Hex) encoding supports strict case,
+    /// other encodings ignore this setting.
+    /// [Custom](crate::HexCharSet::Custom) hexadecimal character sets
+    /// always decode strictly.
+    ///
+    /// With strict case, letters in the other case cause an
+    /// [`InvalidByte`](crate::DecodeError::InvalidByte) error,
+    /// which is useful for checking that a string is in canonical form
+    /// (eg: lowercase-only git object ids).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, decode};
+    ///
+    /// const CFG: Config = Config::HEX_LOWER.strict_case(true);
+    ///
+    /// assert_eq!(decode!("cafe", CFG), &[0xCA, 0xFE]);
+    ///
+    /// match CFG.decode::<2>(b"caFE") {
+    ///     Err(DecodeError::InvalidByte(err)) => assert_eq!(err.index(), 2),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// ```
+    pub const fn strict_case(mut self, strict: bool) -> Self {
+        self.strict_case = strict;
+        self
+    }
+
+    /// Determines whether decoding base 32 accepts letters in either case.
+    /// This is `false` by default.
+    ///
+    /// Only the [`Base32`](crate::Encoding::Base32) encoding supports
+    /// case-insensitive decoding, other encodings ignore this setting.
+    /// The [`Crockford`](crate::B32CharSet::Crockford) character set
+    /// is always decoded case-insensitively.
+    ///
+    /// Letters whose other case is already part of the character set
+    /// (which is only possible with [custom](crate::B32CharSet::Custom) character sets)
+    /// keep decoding to their own value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the letters in the other case are
+    /// the [padding character](Self::padding_char),
+    /// [ignored bytes](Self::ignore_bytes), or the [group separator](Self::group).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, decode};
+    ///
+    /// const CFG: Config = Config::B32.case_insensitive(true);
+    ///
+    /// // TOTP secrets are often written in lowercase
+    /// assert_eq!(decode!("nzswc5a=", CFG), b"neat");
+    /// assert_eq!(decode!("NZswc5A=", CFG), b"neat");
+    ///
+    /// assert!(matches!(
+    ///     Config::B32.decode::<4>(b"nzswc5a="),
+    ///     Err(DecodeError::InvalidByte(_)),
+    /// ));
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn case_insensitive(mut self, insensitive: bool) -> Self {
+        self.case_insensitive = insensitive;
+        self.assert_skipped_bytes();
+        self
+    }
+
+    /// Determines whether decoding accepts strings whose last character
+    /// has set bits that aren't part of the decoded bytes.
+    /// This is `false` by default.
+    ///
+    /// Only the [`Base64`](crate::Encoding::Base64) and
+    /// [`Base32`](crate::Encoding::Base32) encodings support allowing excess bits,
+    /// other encodings ignore this setting.
+    ///
+    /// By default, those excess bits cause an
+    /// [`ExcessBits`](crate::DecodeError::ExcessBits) error,
+    /// allowing them is useful for decoding strings from
+    /// producers that don't zero them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, decode};
+    ///
+    /// // `ABC` has `0b10` in its 2 excess bits
+    /// assert!(matches!(
+    ///     Config::B64.decode::<2>(b"ABC"),
+    ///     Err(DecodeError::ExcessBits(_)),
+    /// ));
+    ///
+    /// assert_eq!(decode!("ABC", Config::B64.allow_excess_bits(true)), &[0, 16]);
+    ///
+    /// ```
+    pub const fn allow_excess_bits(mut self, allow: bool) -> Self {
+        self.excess_bits = allow;
+        self
+    }
+
+    /// Determines the order in which the bits of each byte are encoded.
+    /// This is [`BitOrder::MsbFirst`] by default.
+    ///
+    /// Only the [`Base64`](crate::Encoding::Base64),
+    /// [`Base32`](crate::Encoding::Base32), and [`Hex`](crate::Encoding::Hex)
+    /// encodings support the [`LsbFirst`](BitOrder::LsbFirst) bit order,
+    /// other encodings ignore this setting.
+    ///
+    /// With `LsbFirst`, the first character encodes the least significant bits
+    /// of the first byte, the same as the `bit_order` setting of the
+    /// [`data-encoding`](https://docs.rs/data-encoding) crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{BitOrder, Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::HEX.bit_order(BitOrder::LsbFirst);
+    ///
+    /// assert_eq!(encode!(&[0x12, 0x34], CFG), "2143");
+    ///
+    /// assert_eq!(decode!("2143", CFG), &[0x12, 0x34]);
+    ///
+    /// ```
+    pub const fn bit_order(mut self, order: BitOrder) -> Self {
+        self.bit_order = order;
+        self
+    }
+
+    /// Sets the prefix of the whole hexadecimal string, eg: `"0x"`.
+    /// This is `""` by default.
+    ///
+    /// Only the [`Hex`](crate::Encoding::Hex) encoding supports prefixes,
+    /// other encodings ignore this setting.
+    ///
+    /// Decoding requires the string to start with the prefix,
+    /// compared case-insensitively unless [`strict_case`](Self::strict_case) is enabled.
+    /// A mismatched prefix causes an
+    /// [`InvalidByte`](crate::DecodeError::InvalidByte) error
+    /// at the first byte that doesn't match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` isn't ascii.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, decode, encode};
+    ///
+    /// const CFG: Config = Config::HEX_LOWER.hex_prefix("0x");
+    ///
+    /// assert_eq!(encode!(&[0xDE, 0xAD, 0xBE, 0xEF], CFG), "0xdeadbeef");
+    ///
+    /// assert_eq!(decode!("0xdeadbeef", CFG), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    ///
+    /// match CFG.decode::<4>(b"0ydeadbeef") {
+    ///     Err(DecodeError::InvalidByte(err)) => assert_eq!(err.index(), 1),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn hex_prefix(mut self, prefix: &'static str) -> Self {
+        assert_ascii_format("hex_prefix", prefix);
+        self.hex_prefix = prefix;
+        self
+    }
+
+    /// Sets the prefix of every hexadecimal encoded byte, eg: `"0x"` or `"\\x"`.
+    /// This is `""` by default.
+    ///
+    /// Only the [`Hex`](crate::Encoding::Hex) encoding supports byte prefixes,
+    /// other encodings ignore this setting.
+    ///
+    /// Decoding requires every byte to have the prefix,
+    /// compared like [`hex_prefix`](Self::hex_prefix).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` isn't ascii.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::HEX.hex_byte_prefix("0x").hex_byte_separator(", ");
+    ///
+    /// assert_eq!(encode!(&[0xDE, 0xAD, 0xBE, 0xEF], CFG), "0xDE, 0xAD, 0xBE, 0xEF");
+    ///
+    /// assert_eq!(decode!("0xde, 0xad, 0xbe, 0xef", CFG), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn hex_byte_prefix(mut self, prefix: &'static str) -> Self {
+        assert_ascii_format("hex_byte_prefix", prefix);
+        self.hex_byte_prefix = prefix;
+        self
+    }
+
+    /// Sets the separator between hexadecimal encoded bytes, eg: `", "` or `" "`.
+    /// This is `""` by default.
+    ///
+    /// Only the [`Hex`](crate::Encoding::Hex) encoding supports byte separators,
+    /// other encodings ignore this setting.
+    ///
+    /// Decoding requires the separator between every byte,
+    /// and rejects it after the last byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `separator` isn't ascii.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use const_base::{B64CharSet, Config, Encoding, encode};
+    /// use const_base::{Config, DecodeError, decode, encode};
     ///
-    /// // The same as `Config::B64`
-    /// const CFG: Config = Config::new(Encoding::Base64(B64CharSet::Standard));
+    /// const CFG: Config = Config::HEX_LOWER.hex_byte_separator(" ");
     ///
-    /// assert_eq!(encode!("Rust", CFG), "UnVzdA==")
+    /// assert_eq!(encode!(b"hello", CFG), "68 65 6c 6c 6f");
+    ///
+    /// assert_eq!(decode!("68 65 6c 6c 6f", CFG), b"hello");
+    ///
+    /// match CFG.decode::<5>(b"68 65 6c6c 6f") {
+    ///     Err(DecodeError::InvalidByte(err)) => assert_eq!(err.index(), 8),
+    ///     _ => unreachable!(),
+    /// }
     ///
     /// ```
-    pub const fn new(encoding: Encoding) -> Self {
-        Self {
-            encoding,
-            end_padding: true,
-        }
+    #[track_caller]
+    pub const fn hex_byte_separator(mut self, separator: &'static str) -> Self {
+        assert_ascii_format("hex_byte_separator", separator);
+        self.hex_byte_separator = separator;
+        self
     }
 
-    /// Determines whether the string has padding at the end.
-    /// This is `true` by default.
+    /// Determines whether the string has a check symbol at the end.
+    /// This is `false` by default.
     ///
-    /// For each encoding, the strings are padded to a multiple:
+    /// Only the [`Crockford`](crate::B32CharSet::Crockford) base 32 character set
+    /// supports check symbols, other encodings ignore this setting.
     ///
-    /// - Base64: pads to be a multiple of 4 long, with `=`.
-    /// - Base32: pads to be a multiple of 8 long, with `=`.
-    /// - Hexadecimal: requires no padding
+    /// The check symbol is the value of the encoded string
+    /// (interpreted as a base 32 number) modulo 37,
+    /// using these characters for the values 32 to 36: `*~$=U`.
     ///
-    /// # Examples
+    /// When decoding, a check symbol that doesn't match the rest of the string
+    /// causes a [`WrongCheckSymbol`](crate::DecodeError::WrongCheckSymbol) error.
     ///
-    /// ### Base 64
+    /// # Example
     ///
     /// ```rust
-    /// use const_base::{Config, encode};
+    /// use const_base::{Config, DecodeError, decode, encode};
     ///
-    /// assert_eq!(encode!("Rust", Config::B64), "UnVzdA==");
+    /// const CFG: Config = Config::B32_CROCKFORD.check_symbol(true);
     ///
-    /// assert_eq!(encode!("Rust", Config::B64.end_padding(true)), "UnVzdA==");
+    /// assert_eq!(encode!(b"hello", CFG), "D1JPRV3FJ");
     ///
-    /// assert_eq!(encode!("Rust", Config::B64.end_padding(false)), "UnVzdA");
+    /// // hyphens are skipped, and lowercase letters are accepted
+    /// assert_eq!(decode!("d1jp-rv3f-j", CFG), b"hello");
+    ///
+    /// assert!(matches!(
+    ///     CFG.decode::<5>(b"D1JPRV3FQ"),
+    ///     Err(DecodeError::WrongCheckSymbol(_)),
+    /// ));
+    ///
+    /// ```
+    pub const fn check_symbol(mut self, have: bool) -> Self {
+        self.check_symbol = have;
+        self
+    }
+
+    /// Determines whether the encoded bytes have a checksum at the end.
+    /// This is `false` by default.
+    ///
+    /// Only the [`Base58`](crate::Encoding::Base58) encoding supports checksums
+    /// (the Base58Check format), other encodings ignore this setting.
+    ///
+    /// The checksum is the first 4 bytes of the SHA-256 hash of
+    /// the SHA-256 hash of the unencoded bytes.
+    ///
+    /// When decoding, a checksum that doesn't match the rest of the decoded bytes
+    /// causes a [`ChecksumMismatch`](crate::DecodeError::ChecksumMismatch) error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, decode, encode};
+    ///
+    /// const CFG: Config = Config::B58.checksum(true);
+    ///
+    /// assert_eq!(encode!(b"hello", CFG), "2L5B5yqsVG8Vt");
+    ///
+    /// assert_eq!(decode!("2L5B5yqsVG8Vt", CFG), b"hello");
+    ///
+    /// assert!(matches!(
+    ///     CFG.decode::<5>(b"2L5B5yqsVG8Vu"),
+    ///     Err(DecodeError::ChecksumMismatch(_)),
+    /// ));
     ///
     /// ```
+    pub const fn checksum(mut self, have: bool) -> Self {
+        self.checksum = have;
+        self
+    }
+
+    /// Sets the human-readable part of [`Bech32`](crate::Encoding::Bech32) strings.
+    /// This is `""` by default.
     ///
-    /// ### Base 32
+    /// Other encodings ignore this setting.
+    ///
+    /// When encoding, the human-readable part is written in lowercase
+    /// before the `1` separator, and is required to be set,
+    /// otherwise an [`InvalidHrp`](crate::EncodeError::InvalidHrp) error is returned.
+    /// The [`bech32`](Self::bech32) and [`bech32m`](Self::bech32m) constructors
+    /// take the human-readable part as an argument.
+    ///
+    /// When decoding, if the human-readable part is set,
+    /// the decoded string must have that same human-readable part
+    /// (ignoring case), otherwise an
+    /// [`InvalidHrp`](crate::DecodeError::InvalidHrp) error is returned.
+    /// If it's not set, any valid human-readable part is accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hrp` isn't 1 to 83 characters long,
+    /// or if it contains characters outside of the `'!'..='~'` range.
+    ///
+    /// # Example
     ///
     /// ```rust
-    /// use const_base::{Config, encode};
+    /// use const_base::{Bech32Variant, Config, DecodeError, Encoding, decode, encode};
     ///
-    /// assert_eq!(encode!("Rustic", Config::B32), "KJ2XG5DJMM======");
+    /// const CFG: Config = Config::new(Encoding::Bech32(Bech32Variant::Bech32m)).hrp("hi");
     ///
-    /// assert_eq!(encode!("Rustic", Config::B32.end_padding(true)), "KJ2XG5DJMM======");
+    /// assert_eq!(encode!(b"hello", CFG), "hi1dpjkcmr0gsl7u9");
     ///
-    /// assert_eq!(encode!("Rustic", Config::B32.end_padding(false)), "KJ2XG5DJMM");
+    /// assert_eq!(decode!("HI1DPJKCMR0GSL7U9", CFG), b"hello");
+    ///
+    /// assert!(matches!(
+    ///     Config::bech32m("bye").decode::<5>(b"hi1dpjkcmr0gsl7u9"),
+    ///     Err(DecodeError::InvalidHrp(_)),
+    /// ));
     ///
     /// ```
-    pub const fn end_padding(mut self, have: bool) -> Self {
-        self.end_padding = have;
+    #[track_caller]
+    pub const fn hrp(mut self, hrp: &'static str) -> Self {
+        use const_panic::{FmtArg, PanicVal};
+
+        let bytes = hrp.as_bytes();
+
+        if bytes.is_empty() || bytes.len() > crate::bech32::MAX_HRP_LEN {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the human-readable part must be 1 to 83 characters long, "),
+                PanicVal::write_str("found: "),
+                PanicVal::from_str(hrp, FmtArg::DEBUG),
+            ])
+        }
+
+        for_range! {i in 0..bytes.len() =>
+            if !crate::bech32::is_hrp_byte(bytes[i]) {
+                crate::utils::cpanic(&[
+                    PanicVal::write_str("the human-readable part contains an invalid character at offset "),
+                    PanicVal::from_usize(i, FmtArg::DEBUG),
+                    PanicVal::write_str(": "),
+                    PanicVal::from_str(hrp, FmtArg::DEBUG),
+                ])
+            }
+        }
+
+        self.hrp = hrp;
+        self
+    }
+
+    /// Determines whether the encoded string is surrounded by `<~` `~>` delimiters.
+    /// This is `false` by default.
+    ///
+    /// Only the [`Ascii85`](crate::B85CharSet::Ascii85) base 85 character set
+    /// supports delimiters, other encodings ignore this setting.
+    ///
+    /// Decoding Ascii85 accepts strings with or without delimiters,
+    /// regardless of this setting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// const CFG: Config = Config::ASCII85.delimiters(true);
+    ///
+    /// assert_eq!(encode!(b"hello", CFG), "<~BOu!rDZ~>");
+    ///
+    /// assert_eq!(decode!("<~BOu!rDZ~>", CFG), b"hello");
+    /// assert_eq!(decode!("BOu!rDZ", CFG), b"hello");
+    ///
+    /// ```
+    pub const fn delimiters(mut self, have: bool) -> Self {
+        self.delimiters = have;
         self
     }
 
+    // Whether the encoded string is padded with `self.padding`
+    pub(crate) const fn pads(self) -> bool {
+        self.end_padding && self.uses_padding()
+    }
+
+    // Whether padding is stripped from the end of the string when decoding
+    pub(crate) const fn decodes_padding(self) -> bool {
+        self.uses_padding() && (self.end_padding || self.padding_mode.is_some())
+    }
+
+    // Panics if the bytes that are skipped when decoding (ignored bytes,
+    // line endings, and group separators) or the padding character
+    // are decoded by the encoding, or are the same as each other.
+    //
+    // This is called by every setter that changes one of them,
+    // so that the conflicts are found regardless of the order that the setters are called in.
+    #[track_caller]
+    const fn assert_skipped_bytes(self) {
+        use const_panic::{FmtArg, PanicVal};
+
+        let pads = self.uses_padding();
+        let padding = self.padding;
+        let wraps = self.line_width != 0;
+
+        if pads && self.decodes_byte(padding) {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the padding character "),
+                PanicVal::from_char(padding as char, FmtArg::DEBUG),
+                PanicVal::write_str(" is part of the "),
+                PanicVal::write_str(self.encoding.full_name()),
+                PanicVal::write_str(" character set"),
+            ])
+        }
+
+        for_range! {i in 0..self.ignore.len() =>
+            let byte = self.ignore[i];
+
+            if self.decodes_byte(byte) {
+                crate::utils::cpanic(&[
+                    PanicVal::write_str("the ignored byte "),
+                    PanicVal::from_u8(byte, FmtArg::DEBUG),
+                    PanicVal::write_str(" is part of the "),
+                    PanicVal::write_str(self.encoding.full_name()),
+                    PanicVal::write_str(" character set"),
+                ])
+            } else if pads && byte == padding {
+                crate::utils::cpanic(&[
+                    PanicVal::write_str("the ignored byte "),
+                    PanicVal::from_u8(byte, FmtArg::DEBUG),
+                    PanicVal::write_str(" is the padding character"),
+                ])
+            }
+        }
+
+        if wraps && (self.decodes_byte(b'\r') || self.decodes_byte(b'\n')) {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("line endings are part of the "),
+                PanicVal::write_str(self.encoding.full_name()),
+                PanicVal::write_str(" character set"),
+            ])
+        } else if wraps && pads && matches!(padding, b'\r' | b'\n') {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the padding character "),
+                PanicVal::from_char(padding as char, FmtArg::DEBUG),
+                PanicVal::write_str(" is part of the line endings"),
+            ])
+        }
+
+        let separator = self.group_separator;
+        if self.group_size == 0 {
+            // no grouping
+        } else if self.decodes_byte(separator) {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the group separator "),
+                PanicVal::from_u8(separator, FmtArg::DEBUG),
+                PanicVal::write_str(" is part of the "),
+                PanicVal::write_str(self.encoding.full_name()),
+                PanicVal::write_str(" character set"),
+            ])
+        } else if pads && separator == padding {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("the group separator "),
+                PanicVal::from_u8(separator, FmtArg::DEBUG),
+                PanicVal::write_str(" is the padding character"),
+            ])
+        }
+    }
+
+    // Whether `byte` is decoded by the encoding,
+    // including the characters with a special meaning in some encodings.
+    const fn decodes_byte(self, byte: u8) -> bool {
+        use crate::encoding::{CharSetLookup, SKIP_ENC};
+
+        let from_enc = match self.encoding {
+            Encoding::Base64(cset) => cset.lookup().from_enc,
+            Encoding::Base32(B32CharSet::Crockford)
+                if matches!(byte, b'*' | b'~' | b'$' | b'=' | b'U' | b'u') =>
+            {
+                return true
+            }
+            Encoding::Base32(cset) if self.is_case_insensitive() => {
+                let from_enc = cset.lookup().from_enc;
+                return from_enc[byte.to_ascii_lowercase() as usize] < SKIP_ENC
+                    || from_enc[byte.to_ascii_uppercase() as usize] < SKIP_ENC;
+            }
+            Encoding::Base32(cset) => cset.lookup().from_enc,
+            Encoding::Hex(cset) => cset.lookup().from_enc,
+            Encoding::Base58(cset) => cset.lookup().from_enc,
+            Encoding::Base45 => CharSetLookup::<45>::BASE45.from_enc,
+            Encoding::Base85(B85CharSet::Ascii85) if byte == b'z' => return true,
+            Encoding::Base85(cset) => cset.lookup().from_enc,
+            Encoding::Bech32(_) if byte == b'1' => return true,
+            Encoding::Bech32(variant) => variant.lookup().from_enc,
+        };
+
+        // skipped bytes (like the hyphens in Crockford) don't decode to anything
+        from_enc[byte as usize] < SKIP_ENC
+    }
+
+    // The width of wrapped lines, `0` if the encoding doesn't wrap lines
+    pub(crate) const fn wrap_width(self) -> usize {
+        match self.encoding {
+            Encoding::Base64(_) | Encoding::Base32(_) | Encoding::Hex(_) => self.line_width,
+            _ => 0,
+        }
+    }
+
+    // Whether the encoding can have padding at all
+    pub(crate) const fn uses_padding(self) -> bool {
+        match self.encoding {
+            Encoding::Base64(_) => true,
+            Encoding::Base32(cset) => !matches!(cset, B32CharSet::Crockford),
+            Encoding::Hex(_)
+            | Encoding::Base58(_)
+            | Encoding::Base45
+            | Encoding::Base85(_)
+            | Encoding::Bech32(_) => false,
+        }
+    }
+
+    // Whether the encoded string has a check symbol at the end
+    pub(crate) const fn has_check_symbol(self) -> bool {
+        self.check_symbol && matches!(self.encoding, Encoding::Base32(B32CharSet::Crockford))
+    }
+
+    // Whether hexadecimal letters in the other case are rejected when decoding
+    pub(crate) const fn has_strict_case(self) -> bool {
+        self.strict_case && matches!(self.encoding, Encoding::Hex(_))
+    }
+
+    // Whether letters are decoded regardless of their case
+    pub(crate) const fn is_case_insensitive(self) -> bool {
+        self.case_insensitive && matches!(self.encoding, Encoding::Base32(_))
+    }
+
+    // Whether nonzero excess bits in the last character are accepted when decoding
+    pub(crate) const fn allows_excess_bits(self) -> bool {
+        self.excess_bits && matches!(self.encoding, Encoding::Base64(_) | Encoding::Base32(_))
+    }
+
+    // Whether the least significant bits of each byte are encoded first
+    pub(crate) const fn is_lsb_first(self) -> bool {
+        matches!(self.bit_order, BitOrder::LsbFirst)
+            && matches!(
+                self.encoding,
+                Encoding::Base64(_) | Encoding::Base32(_) | Encoding::Hex(_)
+            )
+    }
+
+    // Whether hexadecimal strings have prefixes or separators
+    pub(crate) const fn has_hex_format(self) -> bool {
+        matches!(self.encoding, Encoding::Hex(_))
+            && !(self.hex_prefix.is_empty()
+                && self.hex_byte_prefix.is_empty()
+                && self.hex_byte_separator.is_empty())
+    }
+
+    // Whether the encoded bytes have a checksum at the end
+    pub(crate) const fn has_checksum(self) -> bool {
+        self.checksum && matches!(self.encoding, Encoding::Base58(_))
+    }
+
+    // Whether the encoded string is surrounded by `<~` `~>`
+    pub(crate) const fn has_delimiters(self) -> bool {
+        self.delimiters && matches!(self.encoding, Encoding::Base85(B85CharSet::Ascii85))
+    }
+
     /// A different way to call [`encode`](crate::encode()).
     ///
     /// # Example
@@ -90,7 +1196,7 @@ impl Config {
     pub const fn encode<const OUT: usize>(
         self,
         input: &[u8],
-    ) -> Result<crate::ArrayStr<OUT>, crate::WrongOutputLength> {
+    ) -> Result<crate::ArrayStr<OUT>, crate::EncodeError> {
         crate::encode(input, self)
     }
 
@@ -154,6 +1260,73 @@ impl Config {
     ///
     pub const B32: Self = Self::new(Encoding::Base32(B32CharSet::Standard));
 
+    /// Configuration with the [`Base32`](crate::Encoding::Base32) encoding,
+    /// using the [`Lowercase`](crate::B32CharSet::Lowercase) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"neat", Config::B32_LOWER), "nzswc5a=");
+    ///
+    /// // onion addresses are unpadded
+    /// assert_eq!(encode!(b"neat", Config::B32_LOWER.end_padding(false)), "nzswc5a");
+    /// ```
+    ///
+    pub const B32_LOWER: Self = Self::new(Encoding::Base32(B32CharSet::Lowercase));
+
+    /// Configuration with the [`Base32`](crate::Encoding::Base32) encoding,
+    /// using the [`Hex`](crate::B32CharSet::Hex) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"neat", Config::B32_HEX), "DPIM2T0=");
+    /// ```
+    ///
+    pub const B32_HEX: Self = Self::new(Encoding::Base32(B32CharSet::Hex));
+
+    /// Configuration with the [`Base32`](crate::Encoding::Base32) encoding,
+    /// using the [`Crockford`](crate::B32CharSet::Crockford) character set,
+    /// without a check symbol.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"neat", Config::B32_CROCKFORD), "DSJP2X0");
+    ///
+    /// // `O`/`o` decode as `0`, `I`/`i`/`L`/`l` decode as `1`
+    /// assert_eq!(decode!(b"dsjp-2xo", Config::B32_CROCKFORD), b"neat");
+    /// ```
+    ///
+    pub const B32_CROCKFORD: Self =
+        Self::new(Encoding::Base32(B32CharSet::Crockford)).end_padding(false);
+
+    /// Configuration with the [`Base32`](crate::Encoding::Base32) encoding,
+    /// using the [`ZBase32`](crate::B32CharSet::ZBase32) character set,
+    /// without padding.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"neat", Config::Z_BASE32), "p31sn7y");
+    ///
+    /// assert_eq!(decode!(b"ktoso55f", Config::Z_BASE32), b"Tahoe");
+    /// ```
+    ///
+    pub const Z_BASE32: Self = Self::new(Encoding::Base32(B32CharSet::ZBase32)).end_padding(false);
+
     /// Configuration with the [`Hex`](crate::Encoding::Hex) (hexadecimal) encoding,
     /// using the [`Uppercase`](crate::HexCharSet::Uppercase) character set.
     ///
@@ -187,4 +1360,130 @@ impl Config {
     /// ```
     ///
     pub const HEX_LOWER: Self = Self::new(Encoding::Hex(HexCharSet::Lowercase));
+
+    /// Configuration with the [`Base58`](crate::Encoding::Base58) encoding,
+    /// using the [`Bitcoin`](crate::B58CharSet::Bitcoin) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"hello world", Config::B58), "StV1DL6CwTryKyV");
+    ///
+    /// // leading zero bytes are encoded as `1`s
+    /// assert_eq!(encode!(&[0, 0, 1], Config::B58), "112");
+    /// assert_eq!(decode!("112", Config::B58), &[0, 0, 1]);
+    /// ```
+    ///
+    pub const B58: Self = Self::new(Encoding::Base58(B58CharSet::Bitcoin));
+
+    /// Configuration with the [`Base58`](crate::Encoding::Base58) encoding,
+    /// using the [`Flickr`](crate::B58CharSet::Flickr) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"hello world", Config::B58_FLICKR), "rTu1dk6cWsRYjYu");
+    /// ```
+    ///
+    pub const B58_FLICKR: Self = Self::new(Encoding::Base58(B58CharSet::Flickr));
+
+    /// Configuration with the [`Base58`](crate::Encoding::Base58) encoding,
+    /// using the [`Ripple`](crate::B58CharSet::Ripple) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"hello world", Config::B58_RIPPLE), "StVrDLaUATiyKyV");
+    /// ```
+    ///
+    pub const B58_RIPPLE: Self = Self::new(Encoding::Base58(B58CharSet::Ripple));
+
+    /// Configuration with the [`Base58`](crate::Encoding::Base58) encoding,
+    /// using the [`Bitcoin`](crate::B58CharSet::Bitcoin) character set,
+    /// with a [checksum](Self::checksum) (the Base58Check format).
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode};
+    ///
+    /// // a bitcoin address, the first byte is the version
+    /// const ADDR: &[u8] = decode!("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", Config::B58_CHECK);
+    ///
+    /// assert_eq!(ADDR.len(), 21);
+    /// assert_eq!(ADDR[0], 0);
+    /// ```
+    ///
+    pub const B58_CHECK: Self = Self::B58.checksum(true);
+
+    /// Configuration with the [`Base45`](crate::Encoding::Base45) encoding.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, decode, encode};
+    ///
+    /// assert_eq!(encode!(b"Hello!!", Config::B45), "%69 VD92EX0");
+    ///
+    /// assert_eq!(decode!("QED8WEX0", Config::B45), b"ietf!");
+    /// ```
+    ///
+    pub const B45: Self = Self::new(Encoding::Base45);
+
+    /// Configuration with the [`Base85`](crate::Encoding::Base85) encoding,
+    /// using the [`Ascii85`](crate::B85CharSet::Ascii85) character set,
+    /// without delimiters.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"hello", Config::ASCII85), "BOu!rDZ");
+    ///
+    /// // chunks of zeros are encoded as `z`
+    /// assert_eq!(encode!(&[0, 0, 0, 0, 1], Config::ASCII85), "z!<");
+    /// ```
+    ///
+    pub const ASCII85: Self = Self::new(Encoding::Base85(B85CharSet::Ascii85));
+
+    /// Configuration with the [`Base85`](crate::Encoding::Base85) encoding,
+    /// using the [`Z85`](crate::B85CharSet::Z85) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(&[0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B], Config::Z85), "HelloWorld");
+    /// ```
+    ///
+    pub const Z85: Self = Self::new(Encoding::Base85(B85CharSet::Z85));
+
+    /// Configuration with the [`Base85`](crate::Encoding::Base85) encoding,
+    /// using the [`Rfc1924`](crate::B85CharSet::Rfc1924) character set.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, encode};
+    ///
+    /// assert_eq!(encode!(b"hello", Config::B85), "Xk~0{Zv");
+    /// ```
+    ///
+    pub const B85: Self = Self::new(Encoding::Base85(B85CharSet::Rfc1924));
 }
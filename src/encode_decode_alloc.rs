@@ -0,0 +1,155 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{Config, DecodeError, EncodeError, Encoding};
+
+/// Runtime encoding and decoding into heap-allocated buffers,
+/// requires the `"alloc"` feature.
+///
+/// These methods use the same encoders and decoders as [`encode`](crate::encode())
+/// and [`decode`](crate::decode()), so their output is the same as the compile-time functions.
+impl Config {
+    /// Encodes `input` into a `String` with the encoding determined by this `Config`.
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`encode`](crate::encode()),
+    /// except for [`EncodeError::WrongOutputLength`], which this never returns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, EncodeError};
+    ///
+    /// assert_eq!(Config::B64.encode_to_string(b"hello"), Ok("aGVsbG8=".to_string()));
+    /// assert_eq!(Config::B58.encode_to_string(b"hello"), Ok("Cn8eVZg".to_string()));
+    ///
+    /// assert!(matches!(
+    ///     Config::Z85.encode_to_string(b"hello"),
+    ///     Err(EncodeError::WrongInputLength(_)),
+    /// ));
+    ///
+    /// ```
+    pub fn encode_to_string(self, input: &[u8]) -> Result<String, EncodeError> {
+        let mut out = String::new();
+        self.encode_into_string(input, &mut out)?;
+        Ok(out)
+    }
+
+    /// Encodes `input` with the encoding determined by this `Config`,
+    /// appending the encoded string to `out`.
+    ///
+    /// Returns the length of the appended string,
+    /// `out` is left unchanged when an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`encode_to_string`](Self::encode_to_string).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::Config;
+    ///
+    /// let mut out = String::from("hex: ");
+    ///
+    /// assert_eq!(Config::HEX.encode_into_string(&[0xDE, 0xAD], &mut out), Ok(4));
+    /// assert_eq!(out, "hex: DEAD");
+    ///
+    /// ```
+    pub fn encode_into_string(self, input: &[u8], out: &mut String) -> Result<usize, EncodeError> {
+        // this is the exact length for most encodings,
+        // and the maximum length for encodings whose length depends on the input's contents.
+        let max_len = crate::encoded_len(input.len(), self);
+
+        // SAFETY: the appended bytes are zeros, and the ascii bytes written by
+        //         `encode_to_slice`, the unwritten zeros are truncated afterwards,
+        //         and all of them are truncated on error.
+        let vec = unsafe { out.as_mut_vec() };
+        let start = vec.len();
+        vec.resize(start + max_len, 0);
+
+        match crate::encode_to_slice(input, &mut vec[start..], self) {
+            Ok(len) => {
+                vec.truncate(start + len);
+                Ok(len)
+            }
+            Err(err) => {
+                vec.truncate(start);
+                Err(err)
+            }
+        }
+    }
+
+    /// Decodes `input` into a `Vec<u8>` with the encoding determined by this `Config`.
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`decode`](crate::decode()),
+    /// except for [`DecodeError::WrongOutputLength`], which this never returns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError};
+    ///
+    /// assert_eq!(Config::B64.decode_to_vec(b"aGVsbG8="), Ok(b"hello".to_vec()));
+    ///
+    /// assert!(matches!(
+    ///     Config::B64.decode_to_vec(b"aGV!bG8="),
+    ///     Err(DecodeError::InvalidByte(_)),
+    /// ));
+    ///
+    /// ```
+    pub fn decode_to_vec(self, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+        let mut out = Vec::new();
+        self.decode_into_vec(input, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decodes `input` with the encoding determined by this `Config`,
+    /// appending the decoded bytes to `out`.
+    ///
+    /// Returns the amount of appended bytes,
+    /// `out` is left unchanged when an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// This returns the same errors as [`decode_to_vec`](Self::decode_to_vec).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::Config;
+    ///
+    /// let mut out = b"bytes: ".to_vec();
+    ///
+    /// assert_eq!(Config::HEX.decode_into_vec(b"6869", &mut out), Ok(2));
+    /// assert_eq!(out, b"bytes: hi");
+    ///
+    /// assert!(Config::HEX.decode_into_vec(b"68!9", &mut out).is_err());
+    /// assert_eq!(out, b"bytes: hi");
+    ///
+    /// ```
+    pub fn decode_into_vec(self, input: &[u8], out: &mut Vec<u8>) -> Result<usize, DecodeError> {
+        let max_len = match self.encoding {
+            // computing the exact length of base 58 strings is as slow as decoding them,
+            // every base 58 character decodes into at most one byte.
+            Encoding::Base58(_) => input.len(),
+            _ => crate::decoded_len(input, self),
+        };
+
+        let start = out.len();
+        out.resize(start + max_len, 0);
+
+        match crate::decode_to_slice(input, &mut out[start..], self) {
+            Ok(len) => {
+                out.truncate(start + len);
+                Ok(len)
+            }
+            Err(err) => {
+                out.truncate(start);
+                Err(err)
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Config, DecodeError, Encoding};
+use crate::{Config, DecodeError, EncodeError, Encoding, PaddingMode};
 
 /// Computes the length of the encoded string from the `unencoded_length`,
 /// using the encoding determined by `config`.
@@ -45,14 +45,77 @@ use crate::{Config, DecodeError, Encoding};
 /// assert_eq!(HEX_4, 8);
 /// assert_eq!(HEX_6, 12);
 /// ```
+/// ### Base58
 ///
+/// Because the length of base 58 encoded strings depends on the contents of the input,
+/// this returns the maximum length for that encoding,
+/// use [`encoded_len_of`] to get the exact length.
 ///
+/// ```rust
+/// use const_base::{Config, encoded_len, encoded_len_of};
+///
+/// const MAX: usize = encoded_len(4, Config::B58);
+/// assert_eq!(MAX, 6);
+///
+/// assert_eq!(encoded_len_of(&[0xFF; 4], Config::B58), 6);
+/// assert_eq!(encoded_len_of(&[0x00; 4], Config::B58), 4);
+/// ```
+///
+/// ### Bech32
+///
+/// ```rust
+/// use const_base::{Config, encoded_len};
+///
+/// // 2 characters for the human-readable part, 1 for the separator,
+/// // 8 for the data, and 6 for the checksum.
+/// const LEN: usize = encoded_len(5, Config::bech32("hi"));
+/// assert_eq!(LEN, 17);
+/// ```
 ///
 pub const fn encoded_len(unencoded_length: usize, config: Config) -> usize {
     match config.encoding {
         Encoding::Base64(_) => crate::base_64::encoded_len(unencoded_length, config),
         Encoding::Base32(_) => crate::base_32::encoded_len(unencoded_length, config),
         Encoding::Hex(_) => crate::base_16::encoded_len(unencoded_length, config),
+        Encoding::Base58(_) => crate::base_58::encoded_len(unencoded_length, config),
+        Encoding::Base45 => crate::base_45::encoded_len(unencoded_length, config),
+        Encoding::Base85(_) => crate::base_85::encoded_len(unencoded_length, config),
+        Encoding::Bech32(_) => crate::bech32::encoded_len(unencoded_length, config),
+    }
+}
+
+/// Computes the length of the string obtained from encoding `input`
+/// with the encoding determined by `config`.
+///
+/// For most encodings this is the same as
+/// [`encoded_len(input.len(), config)`](encoded_len),
+/// the exception is Base58, where the length depends on the contents of `input`.
+///
+/// For Base58 inputs longer than about 6 kilobytes,
+/// this returns the maximum length of encoding an input with that many leading zeros,
+/// the exact length is returned for all shorter inputs.
+/// This limit keeps the stack space used to compute the length under 8 KiB.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, encoded_len_of};
+///
+/// const LENS: &[usize] = &[
+///     encoded_len_of(b"hello", Config::B64),
+///     encoded_len_of(b"hello", Config::B58),
+///     encoded_len_of(&[0, 0, 0, 0, 1], Config::B58),
+/// ];
+/// assert_eq!(LENS, [8, 7, 5]);
+///
+/// ```
+pub const fn encoded_len_of(input: &[u8], config: Config) -> usize {
+    match config.encoding {
+        Encoding::Base58(_) => {
+            crate::base_58::encoded_len_of(input, config, crate::base_58::RUNTIME_SCRATCHES)
+        }
+        Encoding::Base85(cset) => crate::base_85::encoded_len_of(input, config, cset),
+        _ => encoded_len(input.len(), config),
     }
 }
 
@@ -61,25 +124,35 @@ pub const fn encoded_len(unencoded_length: usize, config: Config) -> usize {
 ///
 /// # Errors
 ///
-/// This function returns a `WrongOutputLength` error when
-/// `OUT` doesn't equal `encoded_len(input.len(), config)`.
+/// This function returns an [`EncodeError::WrongOutputLength`] error when
+/// `OUT` doesn't equal [`encoded_len_of(input, config)`](encoded_len_of).
+///
+/// For the [`Z85`](crate::B85CharSet::Z85) character set,
+/// this returns an [`EncodeError::WrongInputLength`] error
+/// when the length of `input` isn't a multiple of 4.
+///
+/// For the [`Bech32`](crate::Encoding::Bech32) encoding,
+/// this returns an [`EncodeError::WrongInputLength`] error
+/// when the encoded string would be longer than 90 characters,
+/// and an [`EncodeError::InvalidHrp`] error
+/// when the [human-readable part](crate::Config::hrp) isn't set.
 ///
 /// # Example
 ///
 /// ### Base 64
 ///
 /// ```rust
-/// use const_base::{ArrayStr, Config, WrongOutputLength, encode};
+/// use const_base::{ArrayStr, Config, EncodeError, encode};
 ///
 /// {
 ///     const ENCODED: &ArrayStr<16> =
-///         &WrongOutputLength::unwrap(encode(b"hello worl", Config::B64));
+///         &EncodeError::unwrap(encode(b"hello worl", Config::B64));
 ///
 ///     assert_eq!(ENCODED, "aGVsbG8gd29ybA==");
 /// }
 /// {
 ///     const CFG: Config = Config::B64.end_padding(false);
-///     const ENCODED: &ArrayStr<4> = &WrongOutputLength::unwrap(encode(b"BYE", CFG));
+///     const ENCODED: &ArrayStr<4> = &EncodeError::unwrap(encode(b"BYE", CFG));
 ///
 ///     assert_eq!(ENCODED, "QllF");
 /// }
@@ -88,16 +161,16 @@ pub const fn encoded_len(unencoded_length: usize, config: Config) -> usize {
 /// ### Base 32
 ///
 /// ```rust
-/// use const_base::{ArrayStr, Config, WrongOutputLength, encode};
+/// use const_base::{ArrayStr, Config, EncodeError, encode};
 ///
 /// {
-///     const ENCODED: &ArrayStr<8> = &WrongOutputLength::unwrap(encode(b"fox", Config::B32));
+///     const ENCODED: &ArrayStr<8> = &EncodeError::unwrap(encode(b"fox", Config::B32));
 ///
 ///     assert_eq!(ENCODED, "MZXXQ===");
 /// }
 /// {
 ///     const CFG: Config = Config::B32.end_padding(false);
-///     const ENCODED: &ArrayStr<5> = &WrongOutputLength::unwrap(encode(b"dog", CFG));
+///     const ENCODED: &ArrayStr<5> = &EncodeError::unwrap(encode(b"dog", CFG));
 ///
 ///     assert_eq!(ENCODED, "MRXWO");
 /// }
@@ -106,12 +179,12 @@ pub const fn encoded_len(unencoded_length: usize, config: Config) -> usize {
 /// ### Hexadecimal
 ///
 /// ```rust
-/// use const_base::{ArrayStr, Config, WrongOutputLength, encode};
+/// use const_base::{ArrayStr, Config, EncodeError, encode};
 ///
 /// {
-///     const LOWER: &ArrayStr<8> = &WrongOutputLength::unwrap(encode(b"bluh", Config::HEX_LOWER));
+///     const LOWER: &ArrayStr<8> = &EncodeError::unwrap(encode(b"bluh", Config::HEX_LOWER));
 ///
-///     const UPPER: &ArrayStr<8> = &WrongOutputLength::unwrap(encode(b"bluh", Config::HEX));
+///     const UPPER: &ArrayStr<8> = &EncodeError::unwrap(encode(b"bluh", Config::HEX));
 ///
 ///     assert_eq!(LOWER, "626c7568");
 ///     assert_eq!(UPPER, "626C7568");
@@ -121,11 +194,100 @@ pub const fn encoded_len(unencoded_length: usize, config: Config) -> usize {
 pub const fn encode<const OUT: usize>(
     input: &[u8],
     config: Config,
-) -> Result<crate::ArrayStr<OUT>, crate::WrongOutputLength> {
-    match config.encoding {
-        Encoding::Base64(cset) => crate::base_64::encode(input, config, cset),
-        Encoding::Base32(cset) => crate::base_32::encode(input, config, cset),
-        Encoding::Hex(cset) => crate::base_16::encode(input, config, cset),
+) -> Result<crate::ArrayStr<OUT>, EncodeError> {
+    let mut out = [0u8; OUT];
+
+    match encode_to_slice(input, &mut out, config) {
+        Ok(len) if len == OUT => unsafe {
+            // SAFETY: `encode_to_slice` only writes ascii,
+            //         and it wrote all `OUT` bytes.
+            Ok(crate::ArrayStr::from_utf8_unchecked(out))
+        },
+        Ok(found) => Err(EncodeError::WrongOutputLength(crate::WrongOutputLength {
+            expected: OUT,
+            found,
+        })),
+        Err(EncodeError::WrongOutputLength(err)) => {
+            Err(EncodeError::WrongOutputLength(crate::WrongOutputLength {
+                expected: OUT,
+                found: err.expected,
+            }))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Encodes `input` into the start of `out` with the encoding determined by `config`,
+/// returning the length of the encoded string.
+///
+/// Unlike [`encode`](crate::encode()),
+/// the length of the output doesn't need to be known at compile-time,
+/// `out` can be longer than the encoded string.
+///
+/// The bytes written to `out` are always ascii.
+///
+/// # Errors
+///
+/// This function returns an [`EncodeError::WrongOutputLength`] error when
+/// `out` is shorter than [`encoded_len_of(input, config)`](encoded_len_of),
+/// [`expected`](crate::WrongOutputLength::expected) is that length and
+/// [`found`](crate::WrongOutputLength::found) is `out.len()`.
+///
+/// This returns [`EncodeError::WrongInputLength`] and [`EncodeError::InvalidHrp`] errors in
+/// the same situations as [`encode`](crate::encode()).
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, EncodeError, encode_to_slice};
+///
+/// let mut buffer = [0u8; 16];
+///
+/// let len = encode_to_slice(b"hello", &mut buffer, Config::B64).unwrap();
+/// assert_eq!(&buffer[..len], b"aGVsbG8=");
+///
+/// match encode_to_slice(b"hello", &mut buffer[..4], Config::B64) {
+///     Err(EncodeError::WrongOutputLength(err)) => {
+///         assert_eq!(err.expected(), 8);
+///         assert_eq!(err.found(), 4);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// ```
+///
+/// ### Const
+///
+/// ```rust
+/// use const_base::{Config, EncodeError, encode_to_slice};
+///
+/// const ENCODED: ([u8; 8], usize) = {
+///     let mut buffer = [0u8; 8];
+///     let len = EncodeError::unwrap(encode_to_slice(b"hi", &mut buffer, Config::HEX));
+///     (buffer, len)
+/// };
+///
+/// assert_eq!(&ENCODED.0[..ENCODED.1], b"6869");
+///
+/// ```
+pub const fn encode_to_slice(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+) -> Result<usize, EncodeError> {
+    let res = match config.encoding {
+        Encoding::Base64(cset) => crate::base_64::encode(input, out, config, cset),
+        Encoding::Base32(cset) => crate::base_32::encode(input, out, config, cset),
+        Encoding::Hex(cset) => crate::base_16::encode(input, out, config, cset),
+        Encoding::Base58(cset) => crate::base_58::encode(input, out, config, cset),
+        Encoding::Base45 => crate::base_45::encode(input, out, config),
+        Encoding::Base85(cset) => return crate::base_85::encode(input, out, config, cset),
+        Encoding::Bech32(variant) => return crate::bech32::encode(input, out, config, variant),
+    };
+
+    match res {
+        Ok(len) => Ok(len),
+        Err(err) => Err(EncodeError::WrongOutputLength(err)),
     }
 }
 
@@ -184,11 +346,39 @@ pub const fn __priv_encode<const OUT: usize>(input: &[u8], config: Config) -> cr
 /// assert_eq!(BASE32, [4, 3, 2, 1]);
 ///
 /// ```
+///
+/// ### Base58
+///
+/// The length of the decoded bytes depends on the contents of `encoded`.
+///
+/// ```rust
+/// use const_base::{Config, decoded_len};
+///
+/// const BASE58: &[usize] = &[
+///     decoded_len(b"StV1DL6CwTryKyV", Config::B58),
+///     decoded_len(b"112", Config::B58),
+///     decoded_len(b"zzz", Config::B58),
+/// ];
+/// assert_eq!(BASE58, [11, 3, 3]);
+///
+/// ```
+///
+/// For Base58 inputs longer than about 11 kilobytes,
+/// this returns the maximum length of decoding a string with that many digits,
+/// the exact length is returned for all shorter inputs.
+/// This limit keeps the stack space used to compute the length under 8 KiB.
+///
 pub const fn decoded_len(encoded: &[u8], config: Config) -> usize {
     match config.encoding {
-        Encoding::Base64(_) => crate::base_64::decoded_len(encoded, config),
-        Encoding::Base32(_) => crate::base_32::decoded_len(encoded, config),
-        Encoding::Hex(_) => crate::base_16::decoded_len(encoded, config),
+        Encoding::Base64(cset) => crate::base_64::decoded_len(encoded, config, cset),
+        Encoding::Base32(cset) => crate::base_32::decoded_len(encoded, config, cset),
+        Encoding::Hex(cset) => crate::base_16::decoded_len(encoded, config, cset),
+        Encoding::Base58(cset) => {
+            crate::base_58::decoded_len(encoded, config, cset, crate::base_58::RUNTIME_SCRATCHES)
+        }
+        Encoding::Base45 => crate::base_45::decoded_len(encoded, config),
+        Encoding::Base85(cset) => crate::base_85::decoded_len(encoded, config, cset),
+        Encoding::Bech32(variant) => crate::bech32::decoded_len(encoded, config, variant),
     }
 }
 
@@ -199,13 +389,13 @@ pub const fn decoded_len(encoded: &[u8], config: Config) -> usize {
 /// This function returns these errors:
 ///
 /// - [`DecodeError::InvalidByte`]:
-/// When one of the bytes isn't in the char set for that encoding.
+///   When one of the bytes isn't in the char set for that encoding.
 ///
 /// - [`DecodeError::WrongOutputLength`]:
-/// When `OUT` doesn't equal `decoded_len(input, config)`.
+///   When `OUT` doesn't equal `decoded_len(input, config)`.
 ///
 /// - [`DecodeError::WrongInputLength`]:
-/// When `input.len()` is not a valid length for that encoding.
+///   When `input.len()` is not a valid length for that encoding.
 ///
 /// # Example
 ///
@@ -270,13 +460,96 @@ pub const fn decode<const OUT: usize>(
     input: &[u8],
     config: Config,
 ) -> Result<[u8; OUT], DecodeError> {
+    let mut out = [0u8; OUT];
+
+    match decode_into(input, &mut out, config, true) {
+        Ok(_) => Ok(out),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decodes `input` into the start of `out` with the encoding determined by `config`,
+/// returning the amount of decoded bytes.
+///
+/// Unlike [`decode`](crate::decode()),
+/// the length of the output doesn't need to be known at compile-time,
+/// `out` can be longer than the decoded bytes.
+///
+/// # Errors
+///
+/// This function returns the same errors as [`decode`](crate::decode()),
+/// except that [`DecodeError::WrongOutputLength`] is returned when
+/// `out` is shorter than [`decoded_len(input, config)`](decoded_len).
+///
+/// When an error is returned, the contents of `out` are unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, decode_to_slice};
+///
+/// let mut buffer = [0u8; 16];
+///
+/// let len = decode_to_slice(b"aGVsbG8=", &mut buffer, Config::B64).unwrap();
+/// assert_eq!(&buffer[..len], b"hello");
+///
+/// match decode_to_slice(b"aGVsbG8=", &mut buffer[..3], Config::B64) {
+///     Err(DecodeError::WrongOutputLength(err)) => {
+///         assert_eq!(err.expected(), 5);
+///         assert_eq!(err.found(), 3);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// ```
+///
+/// ### Const
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, decode_to_slice};
+///
+/// const DECODED: ([u8; 8], usize) = {
+///     let mut buffer = [0u8; 8];
+///     let len = DecodeError::unwrap(decode_to_slice(b"6869", &mut buffer, Config::HEX));
+///     (buffer, len)
+/// };
+///
+/// assert_eq!(&DECODED.0[..DECODED.1], b"hi");
+///
+/// ```
+pub const fn decode_to_slice(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+) -> Result<usize, DecodeError> {
+    decode_into(input, out, config, false)
+}
+
+// Decodes `input` into the start of `out`,
+// requiring the decoded bytes to fill all of `out` if `exact_len` is true.
+const fn decode_into(
+    input: &[u8],
+    out: &mut [u8],
+    config: Config,
+    exact_len: bool,
+) -> Result<usize, DecodeError> {
     match config.encoding {
-        Encoding::Base64(cset) => crate::base_64::decode(input, config, cset),
-        Encoding::Base32(cset) => crate::base_32::decode(input, config, cset),
-        Encoding::Hex(_) => crate::base_16::decode(input, config),
+        Encoding::Base64(cset) => crate::base_64::decode(input, out, exact_len, config, cset),
+        Encoding::Base32(cset) => crate::base_32::decode(input, out, exact_len, config, cset),
+        Encoding::Hex(cset) => crate::base_16::decode(input, out, exact_len, config, cset),
+        Encoding::Base58(cset) => crate::base_58::decode(input, out, exact_len, config, cset),
+        Encoding::Base45 => crate::base_45::decode(input, out, exact_len, config),
+        Encoding::Base85(cset) => crate::base_85::decode(input, out, exact_len, config, cset),
+        Encoding::Bech32(variant) => crate::bech32::decode(input, out, exact_len, config, variant),
     }
 }
 
+// Whether `len` decoded bytes can't be written to an `out_len` long output,
+// which they must fill entirely if `exact_len` is true.
+pub(crate) const fn is_wrong_output_len(len: usize, out_len: usize, exact_len: bool) -> bool {
+    len > out_len || exact_len && len != out_len
+}
+
 #[doc(hidden)]
 pub const fn __priv_decode<const OUT: usize>(input: &[u8], config: Config) -> __DecodeResult<OUT> {
     match decode(input, config) {
@@ -315,164 +588,444 @@ pub(crate) const fn encoded_len_bases(
 
     let div = crate::__priv_utils::div_ceil_u64(mult, div) as usize;
 
-    if config.end_padding {
+    if config.pads() {
         crate::__priv_utils::round_up_to_multiple_usize(div, chunk_size)
     } else {
         div
     }
 }
 
-pub(crate) const fn decoded_len_bases(mut input: &[u8], config: Config, mult: u64) -> usize {
-    if config.end_padding {
-        while let [rem @ .., b'='] = input {
-            input = rem;
+// The length of a `len` characters long encoded string after it's
+// wrapped into lines and split into groups by `lay_out`.
+pub(crate) const fn laid_out_len(len: usize, config: Config) -> usize {
+    len + breaks_len(0, len, config)
+}
+
+// The length of the line endings and group separators that `lay_out` inserts
+// before the characters at the positions in the `start..end` range.
+pub(crate) const fn breaks_len(start: usize, end: usize, config: Config) -> usize {
+    // no break is inserted before the first character
+    let start = if start == 0 { 1 } else { start };
+    if end <= start {
+        return 0;
+    }
+
+    let width = config.wrap_width();
+    let size = config.group_size;
+
+    let lines = multiples_in(start, end, width);
+    let groups = match (size, width) {
+        (0, _) => 0,
+        (_, 0) => multiples_in(start, end, size),
+        // a line ending replaces the separator when both fall on the same position
+        _ => multiples_in(start, end, size) - multiples_in(start, end, lcm(size, width)),
+    };
+
+    lines * config.line_ending.as_bytes().len() + groups
+}
+
+// The amount of multiples of `n` in the `start..end` range, `0` if `n` is `0`.
+//
+// `start` must be larger than `0`.
+const fn multiples_in(start: usize, end: usize, n: usize) -> usize {
+    match n {
+        0 => 0,
+        _ => (end - 1) / n - (start - 1) / n,
+    }
+}
+
+const fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let rem = x % y;
+        x = y;
+        y = rem;
+    }
+    a / x * b
+}
+
+// Wraps into lines and splits into groups the `len` characters of `out` starting at `start`,
+// moving them and the `suffix_len` bytes after them towards the end of `out`,
+// which must be at least `start + laid_out_len(len, config) + suffix_len` long.
+//
+// Returns the index after the last laid out byte.
+pub(crate) const fn lay_out(
+    out: &mut [u8],
+    start: usize,
+    len: usize,
+    suffix_len: usize,
+    config: Config,
+) -> usize {
+    let width = config.wrap_width();
+    let size = config.group_size;
+    let end = start + laid_out_len(len, config) + suffix_len;
+
+    if width == 0 && size == 0 {
+        return end;
+    }
+
+    let ending = config.line_ending.as_bytes();
+    let mut out_i = end;
+    let shift = end - (start + len + suffix_len);
+
+    for_range! {_ in 0..suffix_len =>
+        out_i -= 1;
+        out[out_i] = out[out_i - shift];
+    }
+
+    let mut in_i = len;
+    while in_i != 0 {
+        in_i -= 1;
+        out_i -= 1;
+        out[out_i] = out[start + in_i];
+
+        if in_i == 0 {
+            // no break before the first character
+        } else if width != 0 && in_i % width == 0 {
+            let mut ending_i = ending.len();
+            while ending_i != 0 {
+                ending_i -= 1;
+                out_i -= 1;
+                out[out_i] = ending[ending_i];
+            }
+        } else if size != 0 && in_i % size == 0 {
+            out_i -= 1;
+            out[out_i] = config.group_separator;
         }
     }
 
-    let mult = input.len() as u64 * mult;
+    end
+}
+
+// The line ending or group separator that `lay_out` writes before
+// the laid out character at `index`.
+pub(crate) const fn break_before(index: usize, config: &Config) -> &[u8] {
+    let width = config.wrap_width();
+    let size = config.group_size;
+
+    if index == 0 {
+        // no break before the first character
+        &[]
+    } else if width != 0 && index % width == 0 {
+        config.line_ending.as_bytes()
+    } else if size != 0 && index % size == 0 {
+        core::slice::from_ref(&config.group_separator)
+    } else {
+        &[]
+    }
+}
+
+pub(crate) const fn decoded_len_bases(
+    input: &[u8],
+    config: Config,
+    from_enc: &[u8; 256],
+    mult: u64,
+) -> usize {
+    let input = strip_padding(input, config, from_enc).0;
+
+    let mult = significant_len(input, from_enc) as u64 * mult;
 
     (mult / 8) as usize
 }
 
-macro_rules! encode_bases {
-    ($input:ident, $config:ident, $char_set:ident, $encode_non_empty:expr) => {
-        let mut out = [0u8; OUT];
-        let mut out_i = 0usize;
+// Removes the trailing padding (and skipped bytes) from `input`,
+// if the encoding uses padding.
+//
+// Returns the stripped input and the amount of padding characters.
+pub(crate) const fn strip_padding<'a>(
+    mut input: &'a [u8],
+    config: Config,
+    from_enc: &[u8; 256],
+) -> (&'a [u8], usize) {
+    let mut padding = 0;
 
-        let lookup = $char_set.lookup();
+    if config.decodes_padding() {
+        while let [ref rem @ .., last] = *input {
+            if last == config.padding {
+                padding += 1;
+            } else if from_enc[last as usize] != crate::encoding::SKIP_ENC {
+                break;
+            }
+            input = rem;
+        }
+    }
 
-        #[cfg(feature = "__test")]
-        {
-            let mut i = 0;
-            while i < lookup.into_enc.len() {
-                assert!(lookup.into_enc[i] < 128);
-                i += 1;
+    (input, padding)
+}
+
+// Removes the trailing padding from `input`,
+// checking that the amount of padding is allowed by `config.padding_mode`.
+pub(crate) const fn strip_checked_padding<'a>(
+    input: &'a [u8],
+    config: Config,
+    from_enc: &[u8; 256],
+    chunk_size: usize,
+) -> Result<&'a [u8], DecodeError> {
+    let (stripped, found) = strip_padding(input, config, from_enc);
+
+    let expected = match significant_len(stripped, from_enc) % chunk_size {
+        0 => 0,
+        rem => chunk_size - rem,
+    };
+
+    let expected = match config.padding_mode {
+        Some(PaddingMode::Required) if found != expected => expected,
+        Some(PaddingMode::Forbidden) if found != 0 => 0,
+        _ => return Ok(stripped),
+    };
+
+    Err(DecodeError::InvalidPadding(crate::InvalidPadding {
+        expected,
+        found,
+        encoding: config.encoding,
+    }))
+}
+
+// The amount of bytes in `input` that aren't skipped when decoding.
+pub(crate) const fn significant_len(input: &[u8], from_enc: &[u8; 256]) -> usize {
+    let mut len = 0;
+
+    for_range! {i in 0..input.len() =>
+        if from_enc[input[i] as usize] != crate::encoding::SKIP_ENC {
+            len += 1;
+        }
+    }
+
+    len
+}
+
+// Decodes `input` (which must not have padding) into the start of `out`,
+// where every byte that isn't skipped encodes `bits_per_char` bits.
+//
+// `exact_len` is the same as in `decode_into`.
+//
+// Returns the amount of decoded bytes.
+pub(crate) const fn decode_bits(
+    input: &[u8],
+    out: &mut [u8],
+    exact_len: bool,
+    config: Config,
+    from_enc: &[u8; 256],
+    bits_per_char: u32,
+) -> Result<usize, DecodeError> {
+    use crate::encoding::{INVALID_ENC, SKIP_ENC};
+    use crate::{InvalidByte, WrongInputLength, WrongOutputLength};
+
+    let sig_len = significant_len(input, from_enc);
+    let total_bits = sig_len as u64 * bits_per_char as u64;
+
+    // the last byte can't be made up entirely of excess bits
+    if total_bits % 8 >= bits_per_char as u64 {
+        return Err(DecodeError::WrongInputLength(WrongInputLength {
+            length: sig_len,
+            enc: config.encoding,
+        }));
+    } else if is_wrong_output_len((total_bits / 8) as usize, out.len(), exact_len) {
+        return Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: (total_bits / 8) as usize,
+            found: out.len(),
+        }));
+    }
+
+    let mut out_i = 0usize;
+
+    let mut buffer = BitBuffer::new(config.is_lsb_first());
+    let mut last_byte = 0u8;
+
+    for_range! {in_i in 0..input.len() =>
+        let byte = input[in_i];
+        let decoded = from_enc[byte as usize];
+
+        if decoded == INVALID_ENC {
+            return Err(DecodeError::InvalidByte(InvalidByte {
+                index: in_i,
+                byte,
+                as_char: byte as char,
+                encoding: config.encoding,
+            }));
+        } else if decoded != SKIP_ENC {
+            buffer.push(decoded as u32, bits_per_char);
+            last_byte = byte;
+
+            if let Some(b) = buffer.pop(8) {
+                write_into! {out, out_i, b as u8}
             }
         }
+    }
 
-        let output_len = encoded_len($input.len(), $config);
+    if buffer.has_excess_bits() && !config.allows_excess_bits() {
+        return Err(DecodeError::ExcessBits(crate::ExcessBits { last_byte }));
+    }
 
-        if output_len != OUT {
-            return Err(crate::WrongOutputLength {
-                expected: OUT,
-                found: output_len,
-            });
+    Ok(out_i)
+}
+
+// Encodes `input` into `out` starting at `out_i`, least significant bits first,
+// where every character encodes `bits_per_char` bits.
+//
+// Returns the index after the last written character.
+pub(crate) const fn encode_bits_lsb(
+    out: &mut [u8],
+    mut out_i: usize,
+    input: &[u8],
+    into_enc: &[u8],
+    bits_per_char: u32,
+) -> usize {
+    let mut buffer = BitBuffer::new(true);
+
+    for_range! {i in 0..input.len() =>
+        buffer.push(input[i] as u32, 8);
+
+        while let Some(c) = buffer.pop(bits_per_char) {
+            write_into! {out, out_i, into_enc[c as usize]}
         }
+    }
 
-        macro_rules! write_out {
-            ($b:expr) => {
-                write_into! {out, out_i, lookup.into_enc[$b as usize]}
-            };
+    if let Some(c) = buffer.pop_rest(bits_per_char) {
+        write_into! {out, out_i, into_enc[c as usize]}
+    }
+
+    out_i
+}
+
+// The bits that were read from the input, but weren't written to the output yet.
+//
+// This is the state that's kept between the characters (or bytes) of the input
+// by the encoders and decoders that handle them one at a time.
+#[derive(Copy, Clone)]
+pub(crate) struct BitBuffer {
+    bits: u32,
+    len: u32,
+    lsb_first: bool,
+}
+
+impl BitBuffer {
+    pub(crate) const fn new(lsb_first: bool) -> Self {
+        Self {
+            bits: 0,
+            len: 0,
+            lsb_first,
         }
+    }
 
-        if !$input.is_empty() {
-            $encode_non_empty
+    // The amount of bits in the buffer
+    pub(crate) const fn len(&self) -> u32 {
+        self.len
+    }
+
+    // Whether any of the bits in the buffer is set,
+    // which makes them invalid if they're left over at the end of the decoded input.
+    pub(crate) const fn has_excess_bits(&self) -> bool {
+        self.bits != 0
+    }
+
+    // Adds the `len` lowest bits of `value` after the bits in the buffer.
+    pub(crate) const fn push(&mut self, value: u32, len: u32) {
+        if self.lsb_first {
+            self.bits |= value << self.len;
+        } else {
+            self.bits = (self.bits << len) | value;
         }
+        self.len += len;
+    }
 
-        while out_i != OUT {
-            write_into! {out, out_i, b'='}
+    // Removes the first `len` bits from the buffer, if it has that many.
+    pub(crate) const fn pop(&mut self, len: u32) -> Option<u32> {
+        if self.len < len {
+            return None;
         }
 
-        unsafe {
-            // SAFETY: all encodings from this crate produce ascii only
-            // this unsafe code REQUIRES this macro not to be exported
-            Ok(crate::ArrayStr::from_utf8_unchecked(out))
+        self.len -= len;
+
+        if self.lsb_first {
+            let value = self.bits & ((1 << len) - 1);
+            self.bits >>= len;
+            Some(value)
+        } else {
+            let value = self.bits >> self.len;
+            self.bits &= (1 << self.len) - 1;
+            Some(value)
         }
-    };
+    }
+
+    // Removes all the bits from the buffer, padded with zeros to `len` bits,
+    // returns `None` if the buffer is empty.
+    pub(crate) const fn pop_rest(&mut self, len: u32) -> Option<u32> {
+        if self.len == 0 {
+            return None;
+        } else if !self.lsb_first {
+            self.bits <<= len - self.len;
+        }
+
+        self.len = len;
+        self.pop(len)
+    }
 }
-pub(crate) use encode_bases;
 
-macro_rules! decode_bases {
+macro_rules! encode_bases {
     (
-        dollar = $_:tt,
-        $encoding_ctor:expr,
-        $input:ident,
-        $config:ident,
-        $char_set:ident,
-        $is_invalid_length:expr,
-        |$in_i:ident| $decode_non_empty:expr
+        $input:ident, $out:ident, $config:ident, $char_set:ident,
+        bits_per_char = $bits_per_char:expr,
+        $encode_non_empty:expr
+        $(, suffix = |$encoded:ident| $suffix:expr)?
+        $(,)?
     ) => {
-        use crate::encode_decode_shared::make_invalid_byte_err;
-        use crate::{DecodeError, WrongInputLength, WrongOutputLength};
-
-        let mut out = [0u8; OUT];
+        let out = $out;
         let mut out_i = 0usize;
-        let mut $in_i = 0;
-
-        let from_enc = &$char_set.lookup().from_enc;
 
-        let output_len = decoded_len($input, $config);
+        let lookup = $char_set.lookup();
 
-        if $config.end_padding {
-            while let [rem @ .., b'='] = $input {
-                $input = rem;
+        #[cfg(feature = "__test")]
+        {
+            let mut i = 0;
+            while i < lookup.into_enc.len() {
+                assert!(lookup.into_enc[i] < 128);
+                i += 1;
             }
         }
 
-        if $is_invalid_length {
-            return Err(DecodeError::WrongInputLength(WrongInputLength {
-                length: $input.len(),
-                enc: $config.encoding,
-            }));
-        } else if output_len != OUT {
-            return Err(DecodeError::WrongOutputLength(WrongOutputLength {
+        let unwrapped_len = unwrapped_len($input.len(), $config);
+        let output_len = crate::encode_decode_shared::laid_out_len(unwrapped_len, $config);
+
+        if output_len > out.len() {
+            return Err(crate::WrongOutputLength {
                 expected: output_len,
-                found: OUT,
-            }));
+                found: out.len(),
+            });
         }
 
         macro_rules! write_out {
             ($b:expr) => {
-                write_into! {out, out_i, $b}
+                write_into! {out, out_i, lookup.into_enc[$b as usize]}
             };
         }
 
-#[rustfmt::skip]
-                macro_rules! from_encoded {
-            ($_($new:ident = $old:ident),*) => (
-                $_( let $new = from_enc[$old as usize]; )*
-                if $_( $new == crate::encoding::INVALID_ENC )||* {
-                    return Err(make_invalid_byte_err(
-                        &[$_($new),*],
-                        $input,
-                        $in_i,
-                        $encoding_ctor($char_set)
-                    ));
-                }
-            )
-        }
-
-        if !$input.is_empty() {
-            $decode_non_empty
+        if $config.is_lsb_first() {
+            out_i = crate::encode_decode_shared::encode_bits_lsb(
+                out,
+                out_i,
+                $input,
+                &lookup.into_enc,
+                $bits_per_char,
+            );
+        } else if !$input.is_empty() {
+            $encode_non_empty
         }
 
-        Ok(out)
-    };
-}
-pub(crate) use decode_bases;
-
-pub(crate) const fn make_invalid_byte_err(
-    arr: &[u8],
-    input: &[u8],
-    in_i: usize,
-    encoding: crate::Encoding,
-) -> DecodeError {
-    let mut invalid_pos = !0;
-
-    for_range! {i in 0..arr.len() =>
-        if arr[i] == crate::encoding::INVALID_ENC {
-            invalid_pos = i;
-            break;
+        if $config.pads() {
+            while out_i != unwrapped_len {
+                write_into! {out, out_i, $config.padding}
+            }
         }
-    }
 
-    let index = in_i + invalid_pos;
-    let byte = input[invalid_pos];
+        $({
+            let $encoded: &[u8] = out.split_at(out_i).0;
+            if let Some(b) = $suffix {
+                write_into! {out, out_i, b}
+            }
+        })?
 
-    DecodeError::InvalidByte(crate::InvalidByte {
-        index,
-        byte,
-        as_char: byte as char,
-        encoding,
-    })
+        Ok(crate::encode_decode_shared::lay_out(out, 0, out_i, 0, $config))
+    };
 }
+pub(crate) use encode_bases;
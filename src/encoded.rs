@@ -0,0 +1,132 @@
+use core::fmt::{self, Debug, Display, Write};
+
+use crate::{
+    streaming::{encodes_whole, StreamEncoder, WHOLE_LEN},
+    Config,
+};
+
+// The length of the stack buffer that the encoded string is written into,
+// before it's written into the `Formatter`.
+const BUFFER_LEN: usize = 64;
+
+/// Encodes bytes when it's formatted, returned by [`Config::display`].
+///
+/// Both the `Display` and `Debug` impls write the encoded string,
+/// `Debug` writes it as a quoted string literal.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::Config;
+///
+/// let hash = [0xDE, 0xAD, 0xBE, 0xEF];
+///
+/// assert_eq!(format!("{}", Config::HEX_LOWER.display(&hash)), "deadbeef");
+/// assert_eq!(format!("{:?}", Config::HEX_LOWER.display(&hash)), r#""deadbeef""#);
+///
+/// ```
+#[derive(Copy, Clone)]
+pub struct Encoded<'a> {
+    input: &'a [u8],
+    config: Config,
+}
+
+impl Config {
+    /// Returns a value that encodes `input` when it's formatted,
+    /// with the encoding determined by this `Config`.
+    ///
+    /// This doesn't require the length of the encoded string to be known at compile-time,
+    /// the encoded string is written into the formatter in small chunks as it's encoded.
+    ///
+    /// Base58 and Bech32 strings can't be encoded in chunks,
+    /// so they're encoded all at once into a 512 byte stack buffer when formatted.
+    /// Formatting never allocates.
+    ///
+    /// # Errors
+    ///
+    /// Formatting the returned value returns a [`fmt::Error`](core::fmt::Error)
+    /// if [`encode_to_slice`](crate::encode_to_slice) returns
+    /// a [`WrongInputLength`](crate::EncodeError::WrongInputLength) or
+    /// [`InvalidHrp`](crate::EncodeError::InvalidHrp) error for `input`
+    /// (eg: if the length of a Z85 `input` isn't a multiple of 4),
+    /// or if the Base58 string is longer than 512 bytes.
+    /// Note that `to_string` panics when formatting returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::Config;
+    ///
+    /// let cfg = Config::B64.end_padding(false);
+    /// assert_eq!(cfg.display(b"hello").to_string(), "aGVsbG8");
+    ///
+    /// assert_eq!(Config::B58.display(b"hello").to_string(), "Cn8eVZg");
+    ///
+    /// let cfg = Config::HEX.hex_byte_prefix("0x").hex_byte_separator(", ");
+    /// assert_eq!(cfg.display(&[3, 5, 8]).to_string(), "0x03, 0x05, 0x08");
+    ///
+    /// ```
+    pub const fn display(self, input: &[u8]) -> Encoded<'_> {
+        Encoded {
+            input,
+            config: self,
+        }
+    }
+}
+
+impl Encoded<'_> {
+    /// Gets the bytes that are encoded when this is formatted.
+    pub const fn input(&self) -> &[u8] {
+        self.input
+    }
+
+    /// Gets the `Config` that determines how the bytes are encoded.
+    pub const fn config(&self) -> Config {
+        self.config
+    }
+
+    // Encodes the input, passing the encoded string to `write` in chunks.
+    fn encode_with(&self, mut write: impl FnMut(&str) -> fmt::Result) -> fmt::Result {
+        // SAFETY: the encoded strings are ascii
+        const fn as_str(bytes: &[u8]) -> &str {
+            unsafe { core::str::from_utf8_unchecked(bytes) }
+        }
+
+        if encodes_whole(self.config) {
+            let mut buffer = [0u8; WHOLE_LEN];
+            let len = crate::encode_to_slice(self.input, &mut buffer, self.config)
+                .map_err(|_| fmt::Error)?;
+            return write(as_str(&buffer[..len]));
+        }
+
+        StreamEncoder::check_input(self.input.len(), self.config).map_err(|_| fmt::Error)?;
+
+        let mut encoder = StreamEncoder::new(self.config);
+        encoder.end_input();
+
+        let mut input = self.input;
+        let mut buffer = [0u8; BUFFER_LEN];
+
+        loop {
+            let len = encoder.read(&mut input, &mut buffer);
+            if len == 0 {
+                return Ok(());
+            }
+            write(as_str(&buffer[..len]))?;
+        }
+    }
+}
+
+impl Display for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.encode_with(|s| f.write_str(s))
+    }
+}
+
+impl Debug for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        self.encode_with(|s| write!(f, "{}", s.escape_debug()))?;
+        f.write_char('"')
+    }
+}
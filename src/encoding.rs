@@ -1,5 +1,3 @@
-use crate::DecodeError;
-
 /// Determines which encoding is used.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -12,6 +10,59 @@ pub enum Encoding {
 
     /// Hexadecimal encoding (also known as base 16).
     Hex(HexCharSet),
+
+    /// The Base58 encoding.
+    ///
+    /// Unlike the other encodings, this one encodes the input as a big number,
+    /// so the length of the encoded string depends on the contents of the input.
+    /// Every leading zero byte is encoded as the first character of the character set
+    /// (eg: `1` for the [`Bitcoin`](B58CharSet::Bitcoin) character set).
+    Base58(B58CharSet),
+
+    /// The Base45 encoding, from RFC 9285,
+    /// used for QR code payloads such as EU Digital COVID Certificates.
+    ///
+    /// Every 2 bytes of input are encoded as 3 characters,
+    /// a trailing single byte is encoded as 2 characters.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:
+    /// ```
+    Base45,
+
+    /// The Base85 encoding.
+    ///
+    /// Every 4 bytes of input are encoded as 5 characters,
+    /// a trailing partial chunk of `N` bytes is encoded as `N + 1` characters
+    /// (except with the [`Z85`](B85CharSet::Z85) character set,
+    /// which requires the input length to be a multiple of 4).
+    ///
+    /// With the [`Ascii85`](B85CharSet::Ascii85) character set,
+    /// every 4-byte chunk of zeros is encoded as a single `z`,
+    /// so the length of the encoded string depends on the contents of the input.
+    Base85(B85CharSet),
+
+    /// The Bech32 encoding (BIP-173) and its Bech32m variant (BIP-350).
+    ///
+    /// Encoded strings are made up of a human-readable part
+    /// (set with [`Config::bech32`](crate::Config::bech32) or [`Config::hrp`](crate::Config::hrp)),
+    /// a `1` separator, the input encoded with this character set:
+    ///
+    /// ```text
+    /// qpzry9x8gf2tvdw0s3jn54khce6mua7l
+    /// ```
+    ///
+    /// and a 6 character checksum of everything that came before it.
+    ///
+    /// Strings are encoded in lowercase, and decoding accepts either
+    /// all-lowercase or all-uppercase strings, but not mixed case strings.
+    ///
+    /// Like BIP-173 requires, encoded strings are at most 90 characters long,
+    /// longer strings cause [`WrongInputLength`](crate::WrongInputLength) errors
+    /// when encoding and decoding.
+    Bech32(Bech32Variant),
 }
 
 /// Determines which characters are used for the Base64 encoding
@@ -30,13 +81,18 @@ pub enum B64CharSet {
     /// ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_
     /// ```
     UrlSafe,
+    /// A user-supplied character set,
+    /// usually constructed with [`Config::custom_b64`](crate::Config::custom_b64).
+    Custom(CustomCharSet<64>),
 }
 
 /// Determines which characters are used for the Hexadecimal encoding
 ///
-/// Note that decoding is permissive,
+/// Note that decoding is permissive by default,
 /// `HexCharSet::Lowercase` allows `ABCDEF` in the decoded string,
 /// and `HexCharSet::Uppercase` allows `abcdef` in the decoded string.
+/// [`Config::strict_case`](crate::Config::strict_case) makes decoding reject
+/// the letters in the other case.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum HexCharSet {
@@ -53,6 +109,12 @@ pub enum HexCharSet {
     /// ```
     ///
     Uppercase,
+    /// A user-supplied character set,
+    /// usually constructed with [`Config::custom_hex`](crate::Config::custom_hex).
+    ///
+    /// Unlike the other hexadecimal character sets,
+    /// decoding only accepts the characters in the character set.
+    Custom(CustomCharSet<16>),
 }
 
 /// Determines which characters are used for the Base32 encoding
@@ -65,6 +127,212 @@ pub enum B32CharSet {
     /// ABCDEFGHIJKLMNOPQRSTUVWXYZ234567
     /// ```
     Standard,
+    /// The [`Standard`](Self::Standard) character set in lowercase,
+    /// as used by Tor onion addresses among others.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// abcdefghijklmnopqrstuvwxyz234567
+    /// ```
+    Lowercase,
+    /// The "base32hex" character set from RFC 4648,
+    /// which preserves the sort order of the encoded data.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 0123456789ABCDEFGHIJKLMNOPQRSTUV
+    /// ```
+    Hex,
+    /// Douglas Crockford's base 32 character set,
+    /// meant for identifiers that are read and typed by humans.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 0123456789ABCDEFGHJKMNPQRSTVWXYZ
+    /// ```
+    ///
+    /// Decoding is permissive in the way that the Crockford spec requires:
+    /// - lowercase letters are accepted.
+    /// - `I`, `i`, `L`, and `l` are decoded as `1`.
+    /// - `O` and `o` are decoded as `0`.
+    /// - `-` hyphens are skipped.
+    ///
+    /// This character set never uses padding,
+    /// and optionally has a check symbol at the end,
+    /// enabled with [`Config::check_symbol`](crate::Config::check_symbol).
+    Crockford,
+    /// The human-oriented z-base-32 character set,
+    /// as used by Tahoe-LAFS among others.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// ybndrfg8ejkmcpqxot1uwisza345h769
+    /// ```
+    ZBase32,
+    /// A user-supplied character set,
+    /// usually constructed with [`Config::custom_b32`](crate::Config::custom_b32).
+    Custom(CustomCharSet<32>),
+}
+
+/// Determines which characters are used for the Base58 encoding
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum B58CharSet {
+    /// The character set used by Bitcoin.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz
+    /// ```
+    Bitcoin,
+    /// The character set used by Flickr short urls.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ
+    /// ```
+    Flickr,
+    /// The character set used by Ripple.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz
+    /// ```
+    Ripple,
+}
+
+/// Determines which characters are used for the Base85 encoding
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum B85CharSet {
+    /// Adobe's Ascii85 character set, also used by `btoa`.
+    ///
+    /// Uses the characters from `!` to `u` (inclusive).
+    ///
+    /// Every 4-byte chunk of zeros is encoded as `z`,
+    /// and the encoded string can be surrounded by `<~` `~>` delimiters,
+    /// enabled with [`Config::delimiters`](crate::Config::delimiters).
+    /// Decoding accepts strings with or without delimiters.
+    Ascii85,
+    /// The character set from ZeroMQ's Z85 spec.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#
+    /// ```
+    ///
+    /// Unencoded bytes must be a multiple of 4 long,
+    /// and encoded strings must be a multiple of 5 long.
+    Z85,
+    /// The character set from RFC 1924, also used by git and
+    /// Python's `base64.b85encode`.
+    ///
+    /// Uses these characters:
+    ///
+    /// ```text
+    /// 0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~
+    /// ```
+    Rfc1924,
+}
+
+/// Determines which checksum constant is used for the Bech32 encoding
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// The original Bech32 encoding, from BIP-173.
+    Bech32,
+    /// The Bech32m encoding, from BIP-350.
+    Bech32m,
+}
+
+/// A user-supplied character set with `N` characters.
+///
+/// The characters are validated on construction,
+/// they must be unique ascii bytes, and can't be the `=` padding byte.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{B64CharSet, Config, CustomCharSet, Encoding, encode};
+///
+/// // the character set used by bcrypt
+/// const BCRYPT: CustomCharSet<64> =
+///     CustomCharSet::new(b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789");
+///
+/// // The same as `Config::custom_b64(BCRYPT.alphabet())`
+/// const CFG: Config = Config::new(Encoding::Base64(B64CharSet::Custom(BCRYPT)))
+///     .end_padding(false);
+///
+/// assert_eq!(encode!("Rust", CFG), "SlTxb.");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CustomCharSet<const N: usize> {
+    alphabet: &'static [u8; N],
+}
+
+impl<const N: usize> CustomCharSet<N> {
+    /// Constructs a `CustomCharSet` from the characters of its alphabet,
+    /// the position of each character is the value that it encodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` contains non-ascii bytes, duplicate bytes,
+    /// or the `=` padding byte.
+    #[track_caller]
+    pub const fn new(alphabet: &'static [u8; N]) -> Self {
+        use const_panic::{FmtArg, PanicVal};
+
+        for_range! {i in 0..N =>
+            let byte = alphabet[i];
+
+            if !byte.is_ascii() {
+                crate::utils::cpanic(&[
+                    PanicVal::write_str("the alphabet contains a non-ascii byte at offset "),
+                    PanicVal::from_usize(i, FmtArg::DEBUG),
+                    PanicVal::write_str(": "),
+                    PanicVal::from_u8(byte, FmtArg::DEBUG),
+                ])
+            } else if byte == b'=' {
+                crate::utils::cpanic(&[
+                    PanicVal::write_str("the alphabet contains the `=` padding byte at offset "),
+                    PanicVal::from_usize(i, FmtArg::DEBUG),
+                ])
+            }
+
+            for_range! {j in 0..i =>
+                if alphabet[j] == byte {
+                    crate::utils::cpanic(&[
+                        PanicVal::write_str("the alphabet contains the "),
+                        PanicVal::from_char(byte as char, FmtArg::DEBUG),
+                        PanicVal::write_str(" character twice, at offsets "),
+                        PanicVal::from_usize(j, FmtArg::DEBUG),
+                        PanicVal::write_str(" and "),
+                        PanicVal::from_usize(i, FmtArg::DEBUG),
+                    ])
+                }
+            }
+        }
+
+        Self { alphabet }
+    }
+
+    /// Gets the characters of this character set.
+    pub const fn alphabet(self) -> &'static [u8; N] {
+        self.alphabet
+    }
+
+    const fn lookup(self) -> CharSetLookup<N> {
+        CharSetLookup::new(*self.alphabet)
+    }
 }
 
 impl Encoding {
@@ -73,19 +341,40 @@ impl Encoding {
             Encoding::Base64 { .. } => "base-64",
             Encoding::Base32 { .. } => "base-32",
             Encoding::Hex { .. } => "hexadecimal",
+            Encoding::Base58 { .. } => "base-58",
+            Encoding::Base45 => "base-45",
+            Encoding::Base85 { .. } => "base-85",
+            Encoding::Bech32 { .. } => "bech32",
         }
     }
     pub(crate) const fn full_name(self) -> &'static str {
         match self {
             Encoding::Base64(B64CharSet::Standard) => "base-64(standard)",
             Encoding::Base64(B64CharSet::UrlSafe) => "base-64(url-safe)",
+            Encoding::Base64(B64CharSet::Custom(_)) => "base-64(custom)",
             Encoding::Base32(B32CharSet::Standard) => "base-32",
+            Encoding::Base32(B32CharSet::Lowercase) => "base-32(lowercase)",
+            Encoding::Base32(B32CharSet::Hex) => "base-32(hex)",
+            Encoding::Base32(B32CharSet::Crockford) => "base-32(crockford)",
+            Encoding::Base32(B32CharSet::ZBase32) => "base-32(z-base-32)",
+            Encoding::Base32(B32CharSet::Custom(_)) => "base-32(custom)",
             Encoding::Hex(HexCharSet::Uppercase) => "hexadecimal(uppercase)",
             Encoding::Hex(HexCharSet::Lowercase) => "hexadecimal(lowercase)",
+            Encoding::Hex(HexCharSet::Custom(_)) => "hexadecimal(custom)",
+            Encoding::Base58(B58CharSet::Bitcoin) => "base-58(bitcoin)",
+            Encoding::Base58(B58CharSet::Flickr) => "base-58(flickr)",
+            Encoding::Base58(B58CharSet::Ripple) => "base-58(ripple)",
+            Encoding::Base45 => "base-45",
+            Encoding::Base85(B85CharSet::Ascii85) => "base-85(ascii85)",
+            Encoding::Base85(B85CharSet::Z85) => "base-85(z85)",
+            Encoding::Base85(B85CharSet::Rfc1924) => "base-85(rfc1924)",
+            Encoding::Bech32(Bech32Variant::Bech32) => "bech32",
+            Encoding::Bech32(Bech32Variant::Bech32m) => "bech32m",
         }
     }
 }
 
+#[derive(Copy, Clone)]
 pub(crate) struct CharSetLookup<const CHARS: usize> {
     pub(crate) into_enc: [u8; CHARS],
     pub(crate) from_enc: [u8; 256],
@@ -95,17 +384,21 @@ macro_rules! declare_assoc_consts {
     (
         char_set = $char_set:ident,
         characters = $chars:expr,
+        $(custom = $custom:ident,)?
         $(
             ($variant:ident, $assoc:ident, $value:expr)
         )*
     ) => {
 
         impl $char_set {
-            pub(crate) const fn lookup(self) -> &'static CharSetLookup<$chars> {
+            pub(crate) const fn lookup(self) -> CharSetLookup<$chars> {
                 match self {
                     $(
-                        Self::$variant => <CharSetLookup<$chars>>::$assoc,
+                        Self::$variant => *<CharSetLookup<$chars>>::$assoc,
                     )*
+                    $(
+                        Self::$custom(custom) => custom.lookup(),
+                    )?
                 }
             }
         }
@@ -121,6 +414,7 @@ macro_rules! declare_assoc_consts {
 declare_assoc_consts! {
     char_set = B64CharSet,
     characters = 64,
+    custom = Custom,
 
     (Standard, STANDARD, {
         let mut out = [0u8; 64];
@@ -147,6 +441,7 @@ declare_assoc_consts! {
 declare_assoc_consts! {
     char_set = B32CharSet,
     characters = 32,
+    custom = Custom,
 
     (Standard, STANDARD, {
         let mut out = [0u8; 32];
@@ -157,10 +452,115 @@ declare_assoc_consts! {
 
         Self::new(out)
     })
+    (Lowercase, LOWERCASE, {
+        let mut out = Self::STANDARD.into_enc;
+
+        for_range! {i in 0..32 =>
+            out[i] = out[i].to_ascii_lowercase();
+        }
+
+        Self::new(out)
+    })
+    (Hex, HEX, {
+        let mut out = [0u8; 32];
+        let mut out_i = 0usize;
+
+        for_range_inc!{c in b'0', b'9' => write_into!{out, out_i, c} }
+        for_range_inc!{c in b'A', b'V' => write_into!{out, out_i, c} }
+
+        Self::new(out)
+    })
+    (Crockford, CROCKFORD, {
+        let out = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        Self::new(out)
+            .with_lowercase()
+            .with_alias(b'I', b'1')
+            .with_alias(b'i', b'1')
+            .with_alias(b'L', b'1')
+            .with_alias(b'l', b'1')
+            .with_alias(b'O', b'0')
+            .with_alias(b'o', b'0')
+            .with_skipped(b'-')
+    })
+    (ZBase32, Z_BASE32, {
+        Self::new(*b"ybndrfg8ejkmcpqxot1uwisza345h769")
+    })
+}
+
+declare_assoc_consts! {
+    char_set = HexCharSet,
+    characters = 16,
+    custom = Custom,
+
+    (Lowercase, HEX_LOWERCASE, {
+        Self::new(*b"0123456789abcdef").with_uppercase()
+    })
+    (Uppercase, HEX_UPPERCASE, {
+        Self::new(*b"0123456789ABCDEF").with_lowercase()
+    })
+}
+
+declare_assoc_consts! {
+    char_set = B58CharSet,
+    characters = 58,
+
+    (Bitcoin, BITCOIN, {
+        Self::new(*b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+    })
+    (Flickr, FLICKR, {
+        Self::new(*b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ")
+    })
+    (Ripple, RIPPLE, {
+        Self::new(*b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz")
+    })
+}
+
+declare_assoc_consts! {
+    char_set = B85CharSet,
+    characters = 85,
+
+    (Ascii85, ASCII85, {
+        let mut out = [0u8; 85];
+        let mut out_i = 0usize;
+
+        for_range_inc!{c in b'!', b'u' => write_into!{out, out_i, c} }
+
+        Self::new(out)
+    })
+    (Z85, Z85, {
+        Self::new(
+            *b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#"
+        )
+    })
+    (Rfc1924, RFC1924, {
+        Self::new(
+            *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~"
+        )
+    })
+}
+
+impl CharSetLookup<45> {
+    pub(crate) const BASE45: &'static Self =
+        &Self::new(*b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:");
+}
+
+impl Bech32Variant {
+    // Both variants use the same character set
+    pub(crate) const fn lookup(self) -> CharSetLookup<32> {
+        *CharSetLookup::<32>::BECH32
+    }
+}
+
+impl CharSetLookup<32> {
+    const BECH32: &'static Self = &Self::new(*b"qpzry9x8gf2tvdw0s3jn54khce6mua7l").with_uppercase();
 }
 
 pub(crate) const INVALID_ENC: u8 = u8::MAX;
 
+// Marks bytes that are ignored when decoding.
+pub(crate) const SKIP_ENC: u8 = u8::MAX - 1;
+
 impl<const N: usize> CharSetLookup<N> {
     const fn new(into_enc: [u8; N]) -> Self {
         let mut from_enc = [INVALID_ENC; 256];
@@ -171,30 +571,79 @@ impl<const N: usize> CharSetLookup<N> {
 
         Self { from_enc, into_enc }
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////
+    // Makes the lowercase version of every uppercase letter decode to the same value,
+    // unless the lowercase letter already decodes to something.
+    const fn with_lowercase(mut self) -> Self {
+        for_range_inc! {c in b'A', b'Z' =>
+            if self.from_enc[c as usize] != INVALID_ENC
+                && self.from_enc[c.to_ascii_lowercase() as usize] == INVALID_ENC
+            {
+                self.from_enc[c.to_ascii_lowercase() as usize] = self.from_enc[c as usize];
+            }
+        }
+        self
+    }
 
-// Helper struct for checking that the last byte in base64/32
-// doesn't contain set bits in the `excess_bits` lower bits.
-pub(crate) struct CheckExcessBits {
-    pub(crate) last_byte: u8,
-    pub(crate) decoded_byte: u8,
-    pub(crate) excess_bits: u8,
-}
+    // Makes the uppercase version of every lowercase letter decode to the same value,
+    // unless the uppercase letter already decodes to something.
+    const fn with_uppercase(mut self) -> Self {
+        for_range_inc! {c in b'a', b'z' =>
+            if self.from_enc[c as usize] != INVALID_ENC
+                && self.from_enc[c.to_ascii_uppercase() as usize] == INVALID_ENC
+            {
+                self.from_enc[c.to_ascii_uppercase() as usize] = self.from_enc[c as usize];
+            }
+        }
+        self
+    }
+
+    // Makes `alias` decode to the same value as `byte`.
+    const fn with_alias(mut self, alias: u8, byte: u8) -> Self {
+        self.from_enc[alias as usize] = self.from_enc[byte as usize];
+        self
+    }
 
-impl CheckExcessBits {
-    pub(crate) const fn call(self) -> Result<(), DecodeError> {
-        let Self {
-            last_byte,
-            decoded_byte,
-            excess_bits,
-        } = self;
+    const fn with_skipped(mut self, byte: u8) -> Self {
+        self.from_enc[byte as usize] = SKIP_ENC;
+        self
+    }
 
-        if ((decoded_byte >> excess_bits) << excess_bits) == decoded_byte {
-            Ok(())
-        } else {
-            Err(DecodeError::ExcessBits(crate::ExcessBits { last_byte }))
+    // Makes every byte in `bytes` be ignored when decoding.
+    const fn with_skipped_bytes(mut self, bytes: &[u8]) -> Self {
+        for_range! {i in 0..bytes.len() =>
+            self = self.with_skipped(bytes[i]);
         }
+        self
+    }
+
+    // Makes the bytes that `config` ignores be skipped when decoding,
+    // and adjusts which letter cases are decoded.
+    pub(crate) const fn for_decoding(mut self, config: crate::Config) -> Self {
+        if config.has_strict_case() {
+            // only the characters of the character set decode to a value
+            self = Self::new(self.into_enc);
+        }
+
+        if config.is_case_insensitive() {
+            self = self.with_lowercase().with_uppercase();
+        }
+
+        if matches!(
+            config.encoding,
+            Encoding::Base64(_) | Encoding::Base32(_) | Encoding::Hex(_)
+        ) {
+            self = self.with_skipped_bytes(config.ignore);
+        }
+
+        if config.wrap_width() != 0 {
+            self = self.with_skipped_bytes(b"\r\n");
+        }
+
+        if config.group_size != 0 {
+            self = self.with_skipped(config.group_separator);
+        }
+
+        self
     }
 }
@@ -2,6 +2,8 @@
 
 use crate::Encoding;
 
+use core::fmt::{self, Display, Formatter};
+
 /// Error returned by [`decode`](crate::decode())
 #[derive(Debug, PartialEq)]
 #[non_exhaustive]
@@ -26,6 +28,49 @@ pub enum DecodeError {
     /// When the last byte in the slice passed to [`decode`]
     /// has excess set bits that aren't copied to the return value.
     ExcessBits(ExcessBits),
+    /// When the check symbol at the end of the slice passed to [`decode`]
+    /// doesn't match the rest of the slice.
+    ///
+    /// [`decode`]: crate::decode()
+    WrongCheckSymbol(WrongCheckSymbol),
+    /// When the checksum at the end of the decoded bytes
+    /// doesn't match the rest of the decoded bytes.
+    ///
+    /// [`decode`]: crate::decode()
+    ChecksumMismatch(ChecksumMismatch),
+    /// When the human-readable part of a Bech32 string is invalid,
+    /// or isn't the one in the [`Config`](crate::Config).
+    InvalidHrp(InvalidHrp),
+    /// When a chunk of characters in the slice passed to [`decode`]
+    /// encodes a value that's too large for the bytes it decodes into.
+    ///
+    /// [`decode`]: crate::decode()
+    ChunkOverflow(ChunkOverflow),
+    /// When the padding at the end of the slice passed to [`decode`]
+    /// isn't allowed by the [`PaddingMode`](crate::PaddingMode) of the [`Config`](crate::Config).
+    ///
+    /// [`decode`]: crate::decode()
+    InvalidPadding(InvalidPadding),
+}
+
+/// Error returned by [`encode`](crate::encode())
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// When the array returned by [`encode`] isn't the length that the arguments would produce.
+    ///
+    /// [`encode`]: crate::encode()
+    WrongOutputLength(WrongOutputLength),
+    /// When the slice passed to [`encode`] is not a valid length for that encoding.
+    ///
+    /// The input lengths that are wrong for each encoding:
+    /// - Base 85: for the Z85 character set, when `input.len() % 4` isn't `0`.
+    /// - Bech32: when the encoded string would be longer than 90 characters.
+    ///
+    /// [`encode`]: crate::encode()
+    WrongInputLength(WrongInputLength),
+    /// When the [human-readable part](crate::Config::hrp) of a Bech32 `Config` isn't set.
+    InvalidHrp(InvalidHrp),
 }
 
 macro_rules! define_unwrap_self {
@@ -51,6 +96,25 @@ impl DecodeError {
             DecodeError::WrongOutputLength(x) => x.panic(),
             DecodeError::WrongInputLength(x) => x.panic(),
             DecodeError::ExcessBits(x) => x.panic(),
+            DecodeError::WrongCheckSymbol(x) => x.panic(),
+            DecodeError::ChecksumMismatch(x) => x.panic(),
+            DecodeError::InvalidHrp(x) => x.panic(),
+            DecodeError::ChunkOverflow(x) => x.panic(),
+            DecodeError::InvalidPadding(x) => x.panic(),
+        }
+    }
+}
+
+impl EncodeError {
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        match self {
+            EncodeError::WrongOutputLength(x) => x.panic(),
+            EncodeError::WrongInputLength(x) => x.panic(),
+            EncodeError::InvalidHrp(x) => x.panic(),
         }
     }
 }
@@ -178,6 +242,13 @@ macro_rules! wrong_lengths_doc {
         - Base 64: when `input.len() % 4` equals `1`.\n\
         - Base 32: when `input.len() % 8` equals `1`, `3` , or `6`.\n\
         - Base 16: when `input.len() % 2` equals `1`.\n\
+        - Base 58: never, unless it has a checksum, \
+        then when it decodes to less than 4 bytes.\n\
+        - Base 45: when `input.len() % 3` equals `1`.\n\
+        - Base 85: when the amount of characters (not counting `z`s or delimiters) \
+        `% 5` equals `1`, for the Z85 character set: when `input.len() % 5` isn't `0`.\n\
+        - Bech32: when the data after the last `1` is shorter than the 6 character checksum, \
+        or when the string is longer than 90 characters.\n\
         "
     };
 }
@@ -187,7 +258,12 @@ use wrong_lengths_doc;
 ///
 #[doc = wrong_lengths_doc!()]
 ///
+/// This is also returned by [`encode`] when
+/// the Z85 encoding is passed an input whose length isn't a multiple of 4,
+/// or when a Bech32 encoded string would be longer than 90 characters.
+///
 /// [`decode`]: crate::decode()
+/// [`encode`]: crate::encode()
 ///
 /// # Example
 ///
@@ -216,12 +292,13 @@ pub struct WrongInputLength {
 }
 
 impl WrongInputLength {
-    /// The length of the slice argument
+    /// The length of the slice argument,
+    /// not counting padding or bytes that are skipped by the encoding.
     pub const fn length(&self) -> usize {
         self.length
     }
 
-    /// The encoding that was attempted to decode from.
+    /// The encoding that was attempted to decode from, or encode into.
     pub const fn encoding(&self) -> Encoding {
         self.enc
     }
@@ -245,6 +322,9 @@ impl WrongInputLength {
 /// When the last byte in the slice passed to [`decode`]
 /// has excess set bits that aren't copied to the return value.
 ///
+/// Base 64 and base 32 strings with excess bits can be decoded with
+/// [`Config::allow_excess_bits`](crate::Config::allow_excess_bits).
+///
 /// # Example
 ///
 /// ```rust
@@ -291,10 +371,489 @@ impl ExcessBits {
     }
 }
 
+/// When the check symbol at the end of the slice passed to [`decode`]
+/// doesn't match the rest of the slice.
+///
+/// Only the [`Crockford`](crate::B32CharSet::Crockford) base 32 character set
+/// supports check symbols,
+/// with [`Config::check_symbol`](crate::Config::check_symbol) enabled.
+///
+/// [`decode`]: crate::decode()
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, decode};
+///
+/// const CFG: Config = Config::B32_CROCKFORD.check_symbol(true);
+///
+/// assert_eq!(decode::<3>(b"CSQPYQ", CFG).unwrap(), *b"foo");
+///
+/// match decode::<3>(b"CSQPY~", CFG) {
+///     Err(DecodeError::WrongCheckSymbol(err)) => {
+///         assert_eq!(err.index(), 5);
+///         assert_eq!(err.expected(), b'Q');
+///         assert_eq!(err.found(), b'~');
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct WrongCheckSymbol {
+    pub(crate) index: usize,
+    pub(crate) expected: u8,
+    pub(crate) found: u8,
+}
+
+impl WrongCheckSymbol {
+    /// The offset of the check symbol in the slice argument.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The check symbol computed from the rest of the slice argument.
+    pub const fn expected(&self) -> u8 {
+        self.expected
+    }
+
+    /// The check symbol in the slice argument.
+    pub const fn found(&self) -> u8 {
+        self.found
+    }
+
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        use const_panic::{FmtArg, PanicVal};
+
+        crate::utils::cpanic(&[
+            PanicVal::write_str("wrong check symbol at offset "),
+            PanicVal::from_usize(self.index, FmtArg::DEBUG),
+            PanicVal::write_str(", expected "),
+            PanicVal::from_char(self.expected as char, FmtArg::DEBUG),
+            PanicVal::write_str(" but found "),
+            PanicVal::from_char(self.found as char, FmtArg::DEBUG),
+        ])
+    }
+}
+
+/// When the checksum at the end of the decoded bytes
+/// doesn't match the rest of the decoded bytes.
+///
+/// These encodings have checksums:
+///
+/// - [`Base58`](crate::Encoding::Base58):
+///   with [`Config::checksum`](crate::Config::checksum) enabled,
+///   the checksum is 4 bytes long.
+///
+/// - [`Bech32`](crate::Encoding::Bech32):
+///   always has a checksum,
+///   the 30 bit checksum is stored as a big-endian `u32`.
+///
+/// [`decode`]: crate::decode()
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, decode};
+///
+/// assert_eq!(decode::<5>(b"2L5B5yqsVG8Vt", Config::B58_CHECK).unwrap(), *b"hello");
+///
+/// match decode::<5>(b"2L5B5yqsVG8Vu", Config::B58_CHECK) {
+///     Err(DecodeError::ChecksumMismatch(err)) => {
+///         assert_eq!(err.expected(), [0x95, 0x95, 0xC9, 0xDF]);
+///         assert_eq!(err.found(), [0x95, 0x95, 0xC9, 0xE0]);
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ChecksumMismatch {
+    pub(crate) expected: [u8; 4],
+    pub(crate) found: [u8; 4],
+}
+
+impl ChecksumMismatch {
+    /// The checksum computed from the rest of the decoded bytes.
+    pub const fn expected(&self) -> [u8; 4] {
+        self.expected
+    }
+
+    /// The checksum at the end of the decoded bytes.
+    pub const fn found(&self) -> [u8; 4] {
+        self.found
+    }
+
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        use const_panic::{FmtArg, PanicVal};
+
+        crate::utils::cpanic(&[
+            PanicVal::write_str("checksum mismatch, expected 0x"),
+            PanicVal::from_u32(u32::from_be_bytes(self.expected), FmtArg::HEX),
+            PanicVal::write_str(" but found 0x"),
+            PanicVal::from_u32(u32::from_be_bytes(self.found), FmtArg::HEX),
+        ])
+    }
+}
+
+/// When the human-readable part of a Bech32 string is invalid,
+/// or isn't the one in the [`Config`](crate::Config).
+///
+/// The human-readable part is invalid when
+/// there's no `1` separator,
+/// or when it isn't 1 to 83 characters long.
+///
+/// This is also returned by [`encode`](crate::encode()) when
+/// the human-readable part of the `Config` isn't set,
+/// with a [`length`](Self::length) of `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, decode};
+///
+/// const CFG: Config = Config::bech32("bc");
+///
+/// match decode::<0>(b"a12uel5l", CFG) {
+///     Err(DecodeError::InvalidHrp(err)) => {
+///         assert_eq!(err.expected(), "bc");
+///         assert_eq!(err.length(), 1);
+///     }
+///     _ => unreachable!()
+/// }
+///
+/// match decode::<0>(b"qqqqqqqq", CFG) {
+///     Err(DecodeError::InvalidHrp(err)) => {
+///         assert_eq!(err.length(), 0);
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct InvalidHrp {
+    pub(crate) expected: &'static str,
+    pub(crate) length: usize,
+}
+
+impl InvalidHrp {
+    /// The human-readable part in the [`Config`](crate::Config),
+    /// empty if any human-readable part is accepted.
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The length of the human-readable part in the slice argument,
+    /// `0` if there was no `1` separator, or if the error is from encoding.
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        use const_panic::{FmtArg, PanicVal};
+
+        if self.length == 0 || self.length > crate::bech32::MAX_HRP_LEN {
+            crate::utils::cpanic(&[
+                PanicVal::write_str(
+                    "invalid human-readable part, expected 1 to 83 characters before the last `1`, found ",
+                ),
+                PanicVal::from_usize(self.length, FmtArg::DEBUG),
+            ])
+        } else {
+            crate::utils::cpanic(&[
+                PanicVal::write_str("wrong human-readable part, expected "),
+                PanicVal::from_str(self.expected, FmtArg::DEBUG),
+            ])
+        }
+    }
+}
+
+/// When a chunk of characters in the slice passed to [`decode`]
+/// encodes a value that's too large for the bytes it decodes into.
+///
+/// These encodings can have chunks that overflow:
+///
+/// - Base 85: 5 characters encode 4 bytes,
+///   but can encode values larger than `u32::MAX`.
+///
+/// - Base 45: 3 characters encode 2 bytes,
+///   but can encode values larger than `u16::MAX`
+///   (and a trailing 2 characters can encode values larger than `u8::MAX`).
+///
+/// [`decode`]: crate::decode()
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{B85CharSet, Config, DecodeError, Encoding, decode};
+///
+/// assert_eq!(decode::<4>(b"s8W-!", Config::ASCII85), Ok([0xFF; 4]));
+///
+/// match decode::<8>(b"!!!!!s8W-\"", Config::ASCII85) {
+///     Err(DecodeError::ChunkOverflow(err)) => {
+///         assert_eq!(err.index(), 5);
+///         assert_eq!(err.encoding(), Encoding::Base85(B85CharSet::Ascii85));
+///     }
+///     _ => unreachable!()
+/// }
+///
+/// // `FGW` is 65535 in base 45, `GGW` is 65536
+/// assert_eq!(decode::<2>(b"FGW", Config::B45), Ok([0xFF; 2]));
+///
+/// match decode::<4>(b"000GGW", Config::B45) {
+///     Err(DecodeError::ChunkOverflow(err)) => {
+///         assert_eq!(err.index(), 3);
+///         assert_eq!(err.encoding(), Encoding::Base45);
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ChunkOverflow {
+    pub(crate) index: usize,
+    pub(crate) encoding: Encoding,
+}
+
+impl ChunkOverflow {
+    /// The offset of the start of the chunk in the slice argument.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The encoding that was used to decode the slice.
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        use const_panic::{FmtArg, PanicVal};
+
+        crate::utils::cpanic(&[
+            PanicVal::write_str("the chunk at offset "),
+            PanicVal::from_usize(self.index, FmtArg::DEBUG),
+            PanicVal::write_str(" encodes a value that's too large for the "),
+            PanicVal::write_str(self.encoding.full_name()),
+            PanicVal::write_str(" encoding"),
+        ])
+    }
+}
+
+/// When the padding at the end of the slice passed to [`decode`]
+/// isn't allowed by the [`PaddingMode`](crate::PaddingMode) of the [`Config`](crate::Config).
+///
+/// [`decode`]: crate::decode()
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, PaddingMode, decode};
+///
+/// const CFG: Config = Config::B32.padding_mode(PaddingMode::Forbidden);
+///
+/// assert_eq!(decode::<1>(b"MY", CFG), Ok(*b"f"));
+///
+/// match decode::<1>(b"MY======", CFG) {
+///     Err(DecodeError::InvalidPadding(err)) => {
+///         assert_eq!(err.expected(), 0);
+///         assert_eq!(err.found(), 6);
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct InvalidPadding {
+    pub(crate) expected: usize,
+    pub(crate) found: usize,
+    pub(crate) encoding: Encoding,
+}
+
+impl InvalidPadding {
+    /// The amount of padding characters that the slice was expected to end with.
+    pub const fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The amount of padding characters that the slice ends with.
+    pub const fn found(&self) -> usize {
+        self.found
+    }
+
+    /// The encoding that was used to decode the slice.
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    define_unwrap_self! {}
+
+    /// Panics with this error as the message.
+    #[track_caller]
+    pub const fn panic(&self) -> ! {
+        use const_panic::{FmtArg, PanicVal};
+
+        crate::utils::cpanic(&[
+            PanicVal::write_str("expected the "),
+            PanicVal::write_str(self.encoding.full_name()),
+            PanicVal::write_str(" encoded string to end with "),
+            PanicVal::from_usize(self.expected, FmtArg::DEBUG),
+            PanicVal::write_str(" padding characters, found: "),
+            PanicVal::from_usize(self.found, FmtArg::DEBUG),
+        ])
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidByte(x) => Display::fmt(x, f),
+            DecodeError::WrongOutputLength(x) => Display::fmt(x, f),
+            DecodeError::WrongInputLength(x) => Display::fmt(x, f),
+            DecodeError::ExcessBits(x) => Display::fmt(x, f),
+            DecodeError::WrongCheckSymbol(x) => Display::fmt(x, f),
+            DecodeError::ChecksumMismatch(x) => Display::fmt(x, f),
+            DecodeError::InvalidHrp(x) => Display::fmt(x, f),
+            DecodeError::ChunkOverflow(x) => Display::fmt(x, f),
+            DecodeError::InvalidPadding(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::WrongOutputLength(x) => Display::fmt(x, f),
+            EncodeError::WrongInputLength(x) => Display::fmt(x, f),
+            EncodeError::InvalidHrp(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+// These messages are the same as the ones that the `panic` methods panic with.
+
+impl Display for InvalidByte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid byte ({}_u8, the {:?} character) for the {} encoding at offset {}",
+            self.byte,
+            self.as_char,
+            self.encoding.full_name(),
+            self.index,
+        )
+    }
+}
+
+impl Display for WrongOutputLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected output length to be {} but it is {}",
+            self.expected, self.found,
+        )
+    }
+}
+
+impl Display for WrongInputLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid input length for {}: {}",
+            self.enc.name(),
+            self.length,
+        )
+    }
+}
+
+impl Display for ExcessBits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "excess bits in last byte: {}_u8 (the {:?} character)",
+            self.last_byte, self.last_byte as char,
+        )
+    }
+}
+
+impl Display for WrongCheckSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "wrong check symbol at offset {}, expected {:?} but found {:?}",
+            self.index, self.expected as char, self.found as char,
+        )
+    }
+}
+
+impl Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "checksum mismatch, expected 0x{:X} but found 0x{:X}",
+            u32::from_be_bytes(self.expected),
+            u32::from_be_bytes(self.found),
+        )
+    }
+}
+
+impl Display for InvalidHrp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.length == 0 || self.length > crate::bech32::MAX_HRP_LEN {
+            write!(
+                f,
+                "invalid human-readable part, expected 1 to 83 characters before the last `1`, found {}",
+                self.length,
+            )
+        } else {
+            write!(f, "wrong human-readable part, expected {:?}", self.expected)
+        }
+    }
+}
+
+impl Display for ChunkOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the chunk at offset {} encodes a value that's too large for the {} encoding",
+            self.index,
+            self.encoding.full_name(),
+        )
+    }
+}
+
+impl Display for InvalidPadding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected the {} encoded string to end with {} padding characters, found: {}",
+            self.encoding.full_name(),
+            self.expected,
+            self.found,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
 #[doc(hidden)]
 #[track_caller]
 pub const fn __unwrap_encode<const N: usize>(
-    res: Result<crate::ArrayStr<N>, WrongOutputLength>,
+    res: Result<crate::ArrayStr<N>, EncodeError>,
 ) -> crate::ArrayStr<N> {
     match res {
         Ok(x) => x,
@@ -0,0 +1,596 @@
+use crate::{
+    encode_decode_shared::{breaks_len, significant_len, strip_checked_padding, BitBuffer},
+    encoding::{INVALID_ENC, SKIP_ENC},
+    Config, DecodeError, Encoding, InvalidByte, PaddingMode,
+};
+
+// The values of the encodings that `Encoder` and `Decoder` support.
+#[derive(Copy, Clone)]
+struct BitEncoding {
+    bits_per_char: u32,
+    // the length of the encoded string is padded to a multiple of this
+    chunk_size: usize,
+    // the first `2.pow(bits_per_char)` elements are the characters of the encoding
+    into_enc: [u8; 64],
+    from_enc: [u8; 256],
+}
+
+impl BitEncoding {
+    #[track_caller]
+    const fn new(config: Config) -> Self {
+        use const_panic::PanicVal;
+
+        const fn copy_chars<const N: usize>(chars: &[u8; N]) -> [u8; 64] {
+            let mut out = [0u8; 64];
+            for_range! {i in 0..N =>
+                out[i] = chars[i];
+            }
+            out
+        }
+
+        let (bits_per_char, chunk_size, into_enc, from_enc) = match config.encoding {
+            Encoding::Base64(cset) => {
+                let lookup = cset.lookup();
+                (
+                    6,
+                    4,
+                    copy_chars(&lookup.into_enc),
+                    lookup.for_decoding(config).from_enc,
+                )
+            }
+            Encoding::Base32(cset) if !config.has_check_symbol() => {
+                let lookup = cset.lookup();
+                (
+                    5,
+                    8,
+                    copy_chars(&lookup.into_enc),
+                    lookup.for_decoding(config).from_enc,
+                )
+            }
+            Encoding::Hex(cset) if !config.has_hex_format() => {
+                let lookup = cset.lookup();
+                (
+                    4,
+                    1,
+                    copy_chars(&lookup.into_enc),
+                    lookup.for_decoding(config).from_enc,
+                )
+            }
+            _ => crate::utils::cpanic(&[
+                PanicVal::write_str("the "),
+                PanicVal::write_str(config.encoding.full_name()),
+                PanicVal::write_str(
+                    " encoding can't be encoded or decoded incrementally with this `Config`",
+                ),
+            ]),
+        };
+
+        Self {
+            bits_per_char,
+            chunk_size,
+            into_enc,
+            from_enc,
+        }
+    }
+}
+
+// Whether `config` is supported by `Encoder` and `Decoder`.
+pub(crate) const fn is_supported(config: Config) -> bool {
+    match config.encoding {
+        Encoding::Base64(_) => true,
+        Encoding::Base32(_) => !config.has_check_symbol(),
+        Encoding::Hex(_) => !config.has_hex_format(),
+        _ => false,
+    }
+}
+
+#[track_caller]
+const fn assert_out_len(needed: usize, found: usize) {
+    use const_panic::{FmtArg, PanicVal};
+
+    if needed > found {
+        crate::utils::cpanic(&[
+            PanicVal::write_str("expected the output slice to be at least "),
+            PanicVal::from_usize(needed, FmtArg::DEBUG),
+            PanicVal::write_str(" bytes long, but it is "),
+            PanicVal::from_usize(found, FmtArg::DEBUG),
+        ])
+    }
+}
+
+/// Encodes its input incrementally, in any amount of calls to [`feed`](Self::feed).
+///
+/// This supports the [`Base64`](crate::Encoding::Base64),
+/// [`Base32`](crate::Encoding::Base32), and [`Hex`](crate::Encoding::Hex) encodings,
+/// except for base 32 with a [check symbol](crate::Config::check_symbol)
+/// and hexadecimal with [prefixes or separators](crate::Config::hex_prefix).
+///
+/// The concatenation of the strings written by `feed` and [`finish`](Self::finish)
+/// is the same as the one returned by [`encode`](crate::encode())
+/// for the concatenation of all the fed bytes,
+/// regardless of how the input is split between calls to `feed`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, Encoder};
+///
+/// const ENCODED: ([u8; 16], usize) = {
+///     let mut out = [0u8; 16];
+///     let mut len = 0;
+///     let mut encoder = Encoder::new(Config::B64);
+///
+///     len += encoder.feed(b"hel", out.split_at_mut(len).1);
+///     len += encoder.feed(b"lo, ", out.split_at_mut(len).1);
+///     len += encoder.feed(b"wor", out.split_at_mut(len).1);
+///     len += encoder.finish(out.split_at_mut(len).1);
+///
+///     (out, len)
+/// };
+///
+/// assert_eq!(&ENCODED.0[..ENCODED.1], b"aGVsbG8sIHdvcg==");
+///
+/// ```
+#[derive(Copy, Clone)]
+pub struct Encoder {
+    config: Config,
+    encoding: BitEncoding,
+    buffer: BitBuffer,
+    // the amount of characters (including padding) that were written so far,
+    // not counting line endings or group separators
+    chars: usize,
+}
+
+impl Encoder {
+    /// Constructs an `Encoder` for the encoding determined by `config`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `config` isn't supported by `Encoder`,
+    /// the supported configurations are listed in the [type docs](Self).
+    #[track_caller]
+    pub const fn new(config: Config) -> Self {
+        Self {
+            config,
+            encoding: BitEncoding::new(config),
+            buffer: BitBuffer::new(config.is_lsb_first()),
+            chars: 0,
+        }
+    }
+
+    /// The length of the string that [`feed`](Self::feed) writes
+    /// when it's passed an `input_len` long input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, Encoder};
+    ///
+    /// let mut encoder = Encoder::new(Config::B32);
+    ///
+    /// assert_eq!(encoder.feed_len(2), 3);
+    /// encoder.feed(&[1, 2], &mut [0; 3]);
+    ///
+    /// // there's a bit left over from the previous call
+    /// assert_eq!(encoder.feed_len(2), 3);
+    /// ```
+    pub const fn feed_len(&self, input_len: usize) -> usize {
+        let bits = self.buffer.len() as usize + input_len * 8;
+        let end = self.chars + bits / self.encoding.bits_per_char as usize;
+
+        self.laid_out_len(end)
+    }
+
+    /// The length of the string that [`finish`](Self::finish) writes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, Encoder};
+    ///
+    /// let mut encoder = Encoder::new(Config::B64);
+    /// assert_eq!(encoder.finish_len(), 0);
+    ///
+    /// encoder.feed(b"a", &mut [0; 1]);
+    ///
+    /// // the character with the 2 left over bits, and 2 padding characters
+    /// assert_eq!(encoder.finish_len(), 3);
+    /// ```
+    pub const fn finish_len(&self) -> usize {
+        self.laid_out_len(self.finished_chars())
+    }
+
+    // The amount of characters that were written after `finish` is called.
+    const fn finished_chars(&self) -> usize {
+        let end = self.chars + (self.buffer.len() != 0) as usize;
+
+        if self.config.pads() {
+            crate::__priv_utils::round_up_to_multiple_usize(end, self.encoding.chunk_size)
+        } else {
+            end
+        }
+    }
+
+    // The length of the string that's written to get `end` characters
+    const fn laid_out_len(&self, end: usize) -> usize {
+        end - self.chars + breaks_len(self.chars, end, self.config)
+    }
+
+    /// Encodes `input` into the start of `out`, returning the length of the written string.
+    ///
+    /// The bits that don't make up a complete character are kept for the next call,
+    /// the characters that are written at the end of the string are written by
+    /// [`finish`](Self::finish).
+    ///
+    /// The bytes written to `out` are always ascii.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `out` is shorter than
+    /// [`self.feed_len(input.len())`](Self::feed_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, Encoder};
+    ///
+    /// let mut out = [0u8; 8];
+    /// let mut encoder = Encoder::new(Config::HEX);
+    ///
+    /// assert_eq!(encoder.feed(&[0xAB, 0xCD], &mut out), 4);
+    /// assert_eq!(&out[..4], b"ABCD");
+    ///
+    /// assert_eq!(encoder.finish(&mut out), 0);
+    /// ```
+    #[track_caller]
+    pub const fn feed(&mut self, input: &[u8], out: &mut [u8]) -> usize {
+        assert_out_len(self.feed_len(input.len()), out.len());
+
+        let bits_per_char = self.encoding.bits_per_char;
+        let mut out_i = 0;
+
+        for_range! {i in 0..input.len() =>
+            self.buffer.push(input[i] as u32, 8);
+
+            while let Some(c) = self.buffer.pop(bits_per_char) {
+                self.write_char(out, &mut out_i, self.encoding.into_enc[c as usize]);
+            }
+        }
+
+        out_i
+    }
+
+    /// Encodes the bits left over from [`feed`](Self::feed) into the start of `out`,
+    /// followed by the padding, returning the length of the written string.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `out` is shorter than [`self.finish_len()`](Self::finish_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, Encoder};
+    ///
+    /// let mut out = [0u8; 8];
+    /// let mut encoder = Encoder::new(Config::B32);
+    ///
+    /// assert_eq!(encoder.feed(b"fo", &mut out), 3);
+    /// assert_eq!(&out[..3], b"MZX");
+    ///
+    /// assert_eq!(encoder.finish(&mut out), 5);
+    /// assert_eq!(&out[..5], b"Q====");
+    /// ```
+    #[track_caller]
+    pub const fn finish(mut self, out: &mut [u8]) -> usize {
+        assert_out_len(self.finish_len(), out.len());
+
+        let end = self.finished_chars();
+        let mut out_i = 0;
+
+        if let Some(c) = self.buffer.pop_rest(self.encoding.bits_per_char) {
+            self.write_char(out, &mut out_i, self.encoding.into_enc[c as usize]);
+        }
+
+        while self.chars != end {
+            self.write_char(out, &mut out_i, self.config.padding);
+        }
+
+        out_i
+    }
+
+    // Writes `c` into `out`, preceded by a line ending or group separator
+    // if `crate::encode_decode_shared::lay_out` would insert one before it.
+    const fn write_char(&mut self, out: &mut [u8], out_i: &mut usize, c: u8) {
+        let width = self.config.wrap_width();
+        let size = self.config.group_size;
+
+        if self.chars == 0 {
+            // no break before the first character
+        } else if width != 0 && self.chars % width == 0 {
+            let ending = self.config.line_ending.as_bytes();
+            for_range! {i in 0..ending.len() =>
+                out[*out_i + i] = ending[i];
+            }
+            *out_i += ending.len();
+        } else if size != 0 && self.chars % size == 0 {
+            out[*out_i] = self.config.group_separator;
+            *out_i += 1;
+        }
+
+        out[*out_i] = c;
+        *out_i += 1;
+        self.chars += 1;
+    }
+}
+
+/// Decodes its input incrementally, in any amount of calls to [`feed`](Self::feed).
+///
+/// This supports the same configurations as [`Encoder`].
+///
+/// The concatenation of the bytes written by `feed` is the same as the bytes returned by
+/// [`decode`](crate::decode()) for the concatenation of all the fed strings,
+/// regardless of how the input is split between calls to `feed`.
+/// The errors that can only be detected at the end of the input
+/// (like [`DecodeError::ExcessBits`]) are returned by [`finish`](Self::finish).
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, Decoder};
+///
+/// const DECODED: ([u8; 8], usize) = {
+///     let mut out = [0u8; 8];
+///     let mut len = 0;
+///     let mut decoder = Decoder::new(Config::B64);
+///
+///     len += DecodeError::unwrap(decoder.feed(b"aGV", out.split_at_mut(len).1));
+///     len += DecodeError::unwrap(decoder.feed(b"sbG8", out.split_at_mut(len).1));
+///     len += DecodeError::unwrap(decoder.feed(b"=", out.split_at_mut(len).1));
+///     DecodeError::unwrap(decoder.finish());
+///
+///     (out, len)
+/// };
+///
+/// assert_eq!(&DECODED.0[..DECODED.1], b"hello");
+///
+/// ```
+#[derive(Copy, Clone)]
+pub struct Decoder {
+    config: Config,
+    encoding: BitEncoding,
+    buffer: BitBuffer,
+    // the amount of bytes that were fed so far
+    offset: usize,
+    // the amount of characters that were decoded so far,
+    // not counting padding or bytes that are skipped by the encoding
+    chars: usize,
+    last_byte: u8,
+    // the amount of padding characters after the last decoded character,
+    // and the offset of the first one of them
+    padding: usize,
+    padding_index: usize,
+}
+
+impl Decoder {
+    /// Constructs a `Decoder` for the encoding determined by `config`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `config` isn't supported by `Decoder`,
+    /// the supported configurations are listed in the [`Encoder` docs](Encoder).
+    #[track_caller]
+    pub const fn new(config: Config) -> Self {
+        Self {
+            config,
+            encoding: BitEncoding::new(config),
+            buffer: BitBuffer::new(config.is_lsb_first()),
+            offset: 0,
+            chars: 0,
+            last_byte: 0,
+            padding: 0,
+            padding_index: 0,
+        }
+    }
+
+    /// The maximum amount of bytes that [`feed`](Self::feed) writes
+    /// when it's passed an `input_len` long input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, Decoder};
+    ///
+    /// let mut decoder = Decoder::new(Config::B64);
+    ///
+    /// assert_eq!(decoder.feed_len(3), 2);
+    /// decoder.feed(b"aGV", &mut [0; 2]).unwrap();
+    ///
+    /// // there are 2 bits left over from the previous call
+    /// assert_eq!(decoder.feed_len(3), 2);
+    /// ```
+    pub const fn feed_len(&self, input_len: usize) -> usize {
+        let bits = self.buffer.len() as usize + input_len * self.encoding.bits_per_char as usize;
+        bits / 8
+    }
+
+    /// Decodes `input` into the start of `out`, returning the amount of decoded bytes.
+    ///
+    /// The bits that don't make up a complete byte are kept for the next call.
+    ///
+    /// # Errors
+    ///
+    /// This returns a [`DecodeError::InvalidByte`] error when one of the bytes isn't
+    /// in the character set of the encoding,
+    /// its [`index`](crate::InvalidByte::index) is the offset from the start of
+    /// the first input passed to `feed`.
+    ///
+    /// Padding followed by more characters is reported as an invalid byte
+    /// at the offset of the padding.
+    ///
+    /// After an error is returned, the state of the `Decoder` is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `out` is shorter than
+    /// [`self.feed_len(input.len())`](Self::feed_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, Decoder};
+    ///
+    /// let mut out = [0u8; 8];
+    /// let mut decoder = Decoder::new(Config::HEX);
+    ///
+    /// assert_eq!(decoder.feed(b"ABC", &mut out), Ok(1));
+    /// assert_eq!(decoder.feed(b"D", &mut out[1..]), Ok(1));
+    /// assert_eq!(out[..2], [0xAB, 0xCD]);
+    ///
+    /// match decoder.feed(b"EF!", &mut out) {
+    ///     Err(DecodeError::InvalidByte(err)) => assert_eq!(err.index(), 6),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[track_caller]
+    pub const fn feed(&mut self, input: &[u8], out: &mut [u8]) -> Result<usize, DecodeError> {
+        assert_out_len(self.feed_len(input.len()), out.len());
+
+        let decodes_padding = self.config.decodes_padding();
+        let mut out_i = 0;
+
+        let mut in_i = 0;
+        while in_i < input.len() {
+            let byte = input[in_i];
+            let index = self.offset + in_i;
+            let decoded = self.encoding.from_enc[byte as usize];
+            in_i += 1;
+
+            if decoded == SKIP_ENC {
+                // skipped bytes can be anywhere
+            } else if decodes_padding && byte == self.config.padding {
+                if self.padding == 0 {
+                    self.padding_index = index;
+                }
+                self.padding += 1;
+            } else if decoded == INVALID_ENC || self.padding != 0 {
+                let (index, byte) = if self.padding != 0 {
+                    (self.padding_index, self.config.padding)
+                } else {
+                    (index, byte)
+                };
+
+                return Err(DecodeError::InvalidByte(InvalidByte {
+                    index,
+                    byte,
+                    as_char: byte as char,
+                    encoding: self.config.encoding,
+                }));
+            } else {
+                self.buffer
+                    .push(decoded as u32, self.encoding.bits_per_char);
+                self.chars += 1;
+                self.last_byte = byte;
+
+                if let Some(b) = self.buffer.pop(8) {
+                    write_into! {out, out_i, b as u8}
+                }
+            }
+        }
+
+        self.offset += input.len();
+
+        Ok(out_i)
+    }
+
+    /// Checks that the input fed to [`feed`](Self::feed) ended in a valid way.
+    ///
+    /// # Errors
+    ///
+    /// This returns these errors, in the same situations as [`decode`](crate::decode()):
+    ///
+    /// - [`DecodeError::InvalidPadding`]: if the padding isn't allowed by the
+    ///   [`padding_mode`](crate::Config::padding_mode) of the `Config`.
+    ///
+    /// - [`DecodeError::WrongInputLength`]: if the amount of characters
+    ///   isn't a valid length for that encoding.
+    ///
+    /// - [`DecodeError::ExcessBits`]: if the last character has set bits that
+    ///   aren't part of the decoded bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError, Decoder};
+    ///
+    /// let mut decoder = Decoder::new(Config::B64);
+    ///
+    /// assert_eq!(decoder.feed(b"AAAAA", &mut [0; 3]), Ok(3));
+    ///
+    /// assert!(matches!(decoder.finish(), Err(DecodeError::WrongInputLength(_))));
+    /// ```
+    pub const fn finish(self) -> Result<(), DecodeError> {
+        let chunk_size = self.encoding.chunk_size;
+        let found = self.padding;
+
+        let expected = match self.chars % chunk_size {
+            0 => 0,
+            rem => chunk_size - rem,
+        };
+
+        let expected = match self.config.padding_mode {
+            Some(PaddingMode::Required) if found != expected => Some(expected),
+            Some(PaddingMode::Forbidden) if found != 0 => Some(0),
+            _ => None,
+        };
+
+        if let Some(expected) = expected {
+            return Err(DecodeError::InvalidPadding(crate::InvalidPadding {
+                expected,
+                found,
+                encoding: self.config.encoding,
+            }));
+        }
+
+        let bits_per_char = self.encoding.bits_per_char as usize;
+
+        if self.chars * bits_per_char % 8 >= bits_per_char {
+            Err(DecodeError::WrongInputLength(crate::WrongInputLength {
+                length: self.chars,
+                enc: self.config.encoding,
+            }))
+        } else if self.buffer.has_excess_bits() && !self.config.allows_excess_bits() {
+            Err(DecodeError::ExcessBits(crate::ExcessBits {
+                last_byte: self.last_byte,
+            }))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Returns the errors that `decode` returns for `input` before it decodes any bytes,
+    // which `finish` would only return after `input` is fed to this `Decoder`.
+    //
+    // This must be called before anything is fed to this `Decoder`.
+    pub(crate) const fn check_input(&self, input: &[u8]) -> Result<(), DecodeError> {
+        let from_enc = &self.encoding.from_enc;
+
+        let chunk_size = self.encoding.chunk_size;
+        let input = match strip_checked_padding(input, self.config, from_enc, chunk_size) {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+        let bits_per_char = self.encoding.bits_per_char as usize;
+        let chars = significant_len(input, from_enc);
+
+        if chars * bits_per_char % 8 >= bits_per_char {
+            Err(DecodeError::WrongInputLength(crate::WrongInputLength {
+                length: chars,
+                enc: self.config.encoding,
+            }))
+        } else {
+            Ok(())
+        }
+    }
+}
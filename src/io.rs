@@ -0,0 +1,507 @@
+use std::io::{self, Read, Write};
+
+use alloc::vec::Vec;
+
+use crate::{
+    encoding::{CharSetLookup, SKIP_ENC},
+    streaming::{encodes_whole, StreamEncoder},
+    B85CharSet, Config, DecodeError, EncodeError, Encoding,
+};
+
+// The maximum amount of written bytes that are encoded at once.
+const ENCODE_BLOCK: usize = 512;
+
+// The length of the buffer that the written bytes are encoded into,
+// before they're appended to the output.
+const ENCODE_BUFFER: usize = 256;
+
+// The amount of bytes read from the wrapped reader at once.
+const READ_BLOCK: usize = 1024;
+
+// How the encodings that can be decoded incrementally are split into chunks,
+// every chunk of `chars` characters is decoded independently of the other chunks.
+struct Chunking {
+    chars: usize,
+    // maps the bytes of the encoded string to the values they decode to
+    from_enc: [u8; 256],
+}
+
+impl Chunking {
+    // Returns `None` for the configurations where the encoded string can't be split
+    // into independent chunks, either because every character depends on the entire input,
+    // or because of the characters at the start or end of the encoded string.
+    const fn new(config: Config) -> Option<Self> {
+        let (chars, from_enc) = match config.encoding {
+            Encoding::Base64(cset) => (4, cset.lookup().for_decoding(config).from_enc),
+            Encoding::Base32(cset) if !config.has_check_symbol() => {
+                (8, cset.lookup().for_decoding(config).from_enc)
+            }
+            Encoding::Hex(cset) if !config.has_hex_format() => {
+                (2, cset.lookup().for_decoding(config).from_enc)
+            }
+            Encoding::Base45 => (3, CharSetLookup::<45>::BASE45.for_decoding(config).from_enc),
+            Encoding::Base85(cset @ (B85CharSet::Z85 | B85CharSet::Rfc1924)) => {
+                (5, cset.lookup().for_decoding(config).from_enc)
+            }
+            _ => return None,
+        };
+
+        Some(Self { chars, from_enc })
+    }
+}
+
+/// Encodes the bytes written to it, writing the encoded string to the wrapped writer,
+/// requires the `"std"` feature.
+///
+/// The written bytes are encoded as they're written,
+/// except for the bytes that the next characters depend on
+/// (eg: the bits that don't make up a whole base 64 character yet,
+/// or the last partial chunk of Base85),
+/// the end of the encoded string is written by [`finish`](Self::finish).
+///
+/// [`Base58`](crate::Encoding::Base58) and [`Bech32`](crate::Encoding::Bech32)
+/// strings can't be encoded in pieces, because every character depends on the entire input,
+/// so all the written bytes are buffered and then encoded by `finish`.
+///
+/// The encoded string is the same as the one returned by
+/// [`Config::encode_to_string`](crate::Config::encode_to_string)
+/// for the concatenation of all the written bytes.
+///
+/// # Errors
+///
+/// [`EncodeError`]s are returned as errors of the
+/// [`InvalidInput`](std::io::ErrorKind::InvalidInput) kind, which wrap the `EncodeError`.
+///
+/// Errors that writing more bytes can't fix (eg: Bech32 strings that are too long)
+/// are returned by the `write` that causes them, without writing any byte,
+/// every other error is returned by `finish`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, EncoderWriter};
+///
+/// use std::io::Write;
+///
+/// let mut writer = EncoderWriter::new(Vec::new(), Config::B64);
+///
+/// writer.write_all(b"hel")?;
+/// writer.write_all(b"lo")?;
+///
+/// assert_eq!(writer.finish()?, b"aGVsbG8=");
+///
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct EncoderWriter<W: Write> {
+    inner: W,
+    config: Config,
+    // `None` for the configurations that are encoded by `finish`
+    encoder: Option<StreamEncoder>,
+    // the written bytes, for the configurations that are encoded by `finish`
+    pending: Vec<u8>,
+    // the encoded bytes that haven't been written to `inner` yet
+    output: Vec<u8>,
+    // the amount of bytes that were written so far
+    written: usize,
+}
+
+impl<W: Write> EncoderWriter<W> {
+    /// Constructs an `EncoderWriter` that writes the encoded string to `inner`,
+    /// with the encoding determined by `config`.
+    pub const fn new(inner: W, config: Config) -> Self {
+        Self {
+            inner,
+            config,
+            encoder: if encodes_whole(config) {
+                None
+            } else {
+                Some(StreamEncoder::new(config))
+            },
+            pending: Vec::new(),
+            output: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Gets a reference to the wrapped writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Encodes the bytes that haven't been encoded yet,
+    /// writing them (and the padding, if any) to the wrapped writer,
+    /// then flushes and returns the wrapped writer.
+    ///
+    /// Dropping an `EncoderWriter` without calling this method
+    /// loses the end of the encoded string.
+    ///
+    /// # Errors
+    ///
+    /// This returns the errors from the wrapped writer,
+    /// and errors of the [`InvalidInput`](std::io::ErrorKind::InvalidInput) kind
+    /// for the [`EncodeError`]s returned by
+    /// [`Config::encode_to_string`](crate::Config::encode_to_string)
+    /// (eg: if the input of a Z85 `EncoderWriter` isn't a multiple of 4 bytes long).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, EncoderWriter};
+    ///
+    /// use std::io::Write;
+    ///
+    /// let mut writer = EncoderWriter::new(Vec::new(), Config::B32);
+    ///
+    /// writer.write_all(b"hello, ")?;
+    /// assert_eq!(writer.get_ref(), b"NBSWY3DPFQQ");
+    ///
+    /// writer.write_all(b"world")?;
+    /// assert_eq!(writer.finish()?, b"NBSWY3DPFQQHO33SNRSA====");
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_output()?;
+
+        StreamEncoder::check_input(self.written, self.config)?;
+
+        match &mut self.encoder {
+            Some(encoder) => {
+                encoder.end_input();
+                encode_into(encoder, &[], &mut self.output);
+            }
+            None => {
+                let max_len = crate::encoded_len(self.pending.len(), self.config);
+                self.output.resize(max_len, 0);
+
+                let len = crate::encode_to_slice(&self.pending, &mut self.output, self.config)?;
+                self.output.truncate(len);
+            }
+        }
+
+        self.write_output()?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    // Returns the errors that writing more bytes can't fix,
+    // if the amount of written bytes was `written`.
+    const fn check_written(&self, written: usize) -> Result<(), EncodeError> {
+        match self.config.encoding {
+            Encoding::Bech32(_) => crate::bech32::check_encode_input(written, self.config),
+            _ => Ok(()),
+        }
+    }
+
+    // Writes all of `self.output` into the wrapped writer,
+    // keeping the bytes that weren't written if there's an error.
+    fn write_output(&mut self) -> io::Result<()> {
+        let mut written = 0;
+
+        let mut res = Ok(());
+
+        while written != self.output.len() {
+            match self.inner.write(&self.output[written..]) {
+                Ok(0) => {
+                    res = Err(io::ErrorKind::WriteZero.into());
+                    break;
+                }
+                Ok(n) => written += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    res = Err(e);
+                    break;
+                }
+            }
+        }
+
+        self.output.drain(..written);
+        res
+    }
+}
+
+impl<W: Write> Write for EncoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // none of `buf` is consumed if the encoded bytes from previous calls can't be written
+        self.write_output()?;
+
+        let taken = buf.len().min(ENCODE_BLOCK);
+        self.check_written(self.written + taken)?;
+
+        match &mut self.encoder {
+            Some(encoder) => encode_into(encoder, &buf[..taken], &mut self.output),
+            None => self.pending.extend_from_slice(&buf[..taken]),
+        }
+        self.written += taken;
+
+        // the bytes from `buf` are consumed at this point,
+        // the error is returned by the next call to `write` or `flush`,
+        // because the unwritten bytes are kept in `self.output`.
+        let _ = self.write_output();
+
+        Ok(taken)
+    }
+
+    /// Writes the encoded bytes to the wrapped writer,
+    /// then flushes it.
+    ///
+    /// This doesn't write the end of the encoded string,
+    /// which depends on the bytes written afterwards,
+    /// that is written by [`finish`](EncoderWriter::finish).
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_output()?;
+        self.inner.flush()
+    }
+}
+
+// Encodes `input` with `encoder`, appending the encoded bytes to `out`.
+fn encode_into(encoder: &mut StreamEncoder, mut input: &[u8], out: &mut Vec<u8>) {
+    let mut buffer = [0u8; ENCODE_BUFFER];
+    loop {
+        let len = encoder.read(&mut input, &mut buffer);
+        out.extend_from_slice(&buffer[..len]);
+
+        if len != buffer.len() {
+            break;
+        }
+    }
+}
+
+/// Decodes the encoded string read from the wrapped reader,
+/// requires the `"std"` feature.
+///
+/// The encoded string is decoded in chunks as soon as they are read
+/// (eg: 4 characters for base 64, 8 characters for base 32),
+/// except for the last chunk, which is decoded once the wrapped reader reaches its end.
+///
+/// These configurations can't be decoded in chunks,
+/// so the entire encoded string is read before it's decoded:
+/// - [`Base58`](crate::Encoding::Base58) and [`Bech32`](crate::Encoding::Bech32)
+/// - [`Ascii85`](crate::B85CharSet::Ascii85)
+/// - base 32 with a [check symbol](crate::Config::check_symbol)
+/// - hexadecimal with any of the [prefixes or separators](crate::Config::hex_prefix)
+///
+/// The decoded bytes are the same as the ones returned by
+/// [`Config::decode_to_vec`](crate::Config::decode_to_vec) for the entire encoded string.
+///
+/// # Errors
+///
+/// [`DecodeError`]s are returned as errors of the
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) kind,
+/// which wrap the `DecodeError`.
+///
+/// The offsets in the `DecodeError` (eg: [`InvalidByte::index`](crate::InvalidByte::index))
+/// are from the start of the encoded string.
+///
+/// Because every chunk is decoded as soon as it's read,
+/// if the encoded string has multiple errors,
+/// the returned error can be a different one than the one returned by
+/// [`decode_to_vec`](crate::Config::decode_to_vec).
+///
+/// # Example
+///
+/// ```rust
+/// use const_base::{Config, DecodeError, DecoderReader};
+///
+/// use std::io::Read;
+///
+/// let mut out = Vec::new();
+/// DecoderReader::new(&b"aGVsbG8="[..], Config::B64).read_to_end(&mut out)?;
+/// assert_eq!(out, b"hello");
+///
+/// let mut reader = DecoderReader::new(&b"aGVsbG8gd29ybGQ!"[..], Config::B64);
+///
+/// let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+/// match err.get_ref().unwrap().downcast_ref::<DecodeError>() {
+///     Some(DecodeError::InvalidByte(err)) => assert_eq!(err.index(), 15),
+///     _ => unreachable!(),
+/// }
+///
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct DecoderReader<R: Read> {
+    inner: R,
+    config: Config,
+    chunking: Option<Chunking>,
+    // the encoded bytes that were read but haven't been decoded yet
+    input: Vec<u8>,
+    // the offset of the start of `self.input` in the encoded string
+    offset: usize,
+    // the amount of characters (not counting skipped bytes) that were decoded so far
+    chars: usize,
+    // the decoded bytes, starting at `self.output_pos`, that haven't been read yet
+    output: Vec<u8>,
+    output_pos: usize,
+    // whether the wrapped reader reached its end
+    eof: bool,
+    // whether the entire encoded string was decoded
+    done: bool,
+}
+
+impl<R: Read> DecoderReader<R> {
+    /// Constructs a `DecoderReader` that decodes the encoded string read from `inner`,
+    /// with the encoding determined by `config`.
+    pub const fn new(inner: R, config: Config) -> Self {
+        Self {
+            inner,
+            config,
+            chunking: Chunking::new(config),
+            input: Vec::new(),
+            offset: 0,
+            chars: 0,
+            output: Vec::new(),
+            output_pos: 0,
+            eof: false,
+            done: false,
+        }
+    }
+
+    /// Gets a reference to the wrapped reader.
+    pub const fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps this `DecoderReader`, returning the wrapped reader.
+    ///
+    /// The bytes that were read from the wrapped reader but weren't decoded yet are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // Decodes the chunks in `self.input` into `self.output`,
+    // leaving the input that can't be decoded yet in `self.input`.
+    //
+    // The input is only removed after it's successfully decoded,
+    // so that errors are returned again by subsequent reads.
+    fn decode_input(&mut self) -> Result<(), DecodeError> {
+        if self.eof {
+            self.output.clear();
+            self.output_pos = 0;
+
+            let res = self.config.decode_into_vec(&self.input, &mut self.output);
+            let chars_before = self.chars;
+            self.map_offsets(res, chars_before)?;
+
+            self.done = true;
+            return Ok(());
+        }
+
+        let Some(chunking) = &self.chunking else {
+            return Ok(());
+        };
+
+        // padding is only valid at the end of the encoded string,
+        // so the input from the first padding character onwards is kept until the end is read.
+        let padding = self.config.padding;
+        let end = self
+            .input
+            .iter()
+            .position(|&b| self.config.decodes_padding() && b == padding)
+            .unwrap_or(self.input.len());
+        let unpadded = &self.input[..end];
+
+        let total = unpadded
+            .iter()
+            .filter(|&&b| chunking.from_enc[b as usize] != SKIP_ENC)
+            .count();
+
+        // the last chunk is kept until more characters (or the end) are read,
+        // because it's only valid for it to be partial or padded at the end.
+        let chars = total.saturating_sub(1) / chunking.chars * chunking.chars;
+        if chars == 0 {
+            return Ok(());
+        }
+
+        let mut counted = 0;
+        let split = self
+            .input
+            .iter()
+            .position(|&b| {
+                counted += (chunking.from_enc[b as usize] != SKIP_ENC) as usize;
+                counted == chars
+            })
+            .map_or(self.input.len(), |i| i + 1);
+
+        // padding is only valid in the last chunk
+        let mut chunk_config = self.config;
+        chunk_config.end_padding = false;
+        chunk_config.padding_mode = None;
+
+        self.output.clear();
+        self.output_pos = 0;
+
+        let res = chunk_config.decode_into_vec(&self.input[..split], &mut self.output);
+        self.map_offsets(res, 0)?;
+
+        self.input.drain(..split);
+        self.offset += split;
+        self.chars += chars;
+
+        Ok(())
+    }
+
+    // Makes the offsets in errors relative to the start of the encoded string.
+    fn map_offsets(
+        &self,
+        res: Result<usize, DecodeError>,
+        chars_before: usize,
+    ) -> Result<usize, DecodeError> {
+        res.map_err(|err| match err {
+            DecodeError::InvalidByte(mut err) => {
+                err.index += self.offset;
+                DecodeError::InvalidByte(err)
+            }
+            DecodeError::ChunkOverflow(mut err) => {
+                err.index += self.offset;
+                DecodeError::ChunkOverflow(err)
+            }
+            DecodeError::WrongInputLength(mut err) => {
+                // base 85 reports the length including skipped bytes
+                err.length += match self.config.encoding {
+                    Encoding::Base85(_) => self.offset,
+                    _ => chars_before,
+                };
+                DecodeError::WrongInputLength(err)
+            }
+            err => err,
+        })
+    }
+}
+
+impl<R: Read> Read for DecoderReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let decoded = &self.output[self.output_pos..];
+            if !decoded.is_empty() || self.done || buf.is_empty() {
+                let len = decoded.len().min(buf.len());
+                buf[..len].copy_from_slice(&decoded[..len]);
+                self.output_pos += len;
+                return Ok(len);
+            }
+
+            let mut block = [0u8; READ_BLOCK];
+            let read = self.inner.read(&mut block)?;
+
+            self.input.extend_from_slice(&block[..read]);
+            self.eof = read == 0;
+
+            self.decode_input()?;
+        }
+    }
+}
+
+/// Converts the error into an [`InvalidData`](std::io::ErrorKind::InvalidData) error,
+/// requires the `"std"` feature.
+impl From<DecodeError> for io::Error {
+    fn from(err: DecodeError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Converts the error into an [`InvalidInput`](std::io::ErrorKind::InvalidInput) error,
+/// requires the `"std"` feature.
+impl From<EncodeError> for io::Error {
+    fn from(err: EncodeError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
@@ -0,0 +1,312 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    decode_to_slice, decoded_len, encoded_len,
+    streaming::{whole_slice, StreamEncoder, WholeBuffer, EMPTY_WHOLE_BUFFER},
+    Config, DecodeError, Decoder, Encoding,
+};
+
+// The amount of characters that are decoded at once,
+// which are at most 6 bytes long once decoded.
+const DECODE_PIECE_LEN: usize = 8;
+
+impl Config {
+    /// Returns an iterator over the bytes of `input` encoded
+    /// with the encoding determined by this `Config`.
+    ///
+    /// The iterator yields the same bytes as [`encode`](crate::encode()),
+    /// encoding `input` in small chunks as it's iterated over.
+    ///
+    /// Base58 and Bech32 strings can't be encoded in chunks,
+    /// so they're encoded all at once on the first call to `next`,
+    /// into a `Vec` if the `"alloc"` feature is enabled,
+    /// otherwise into a 512 byte buffer in the iterator.
+    ///
+    /// The [`size_hint`](Iterator::size_hint) of the iterator is exact,
+    /// except for Base58 strings before the first call to `next`,
+    /// because their length depends on the contents of `input`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if [`encode_to_slice`](crate::encode_to_slice) returns
+    /// a [`WrongInputLength`](crate::EncodeError::WrongInputLength) or
+    /// [`InvalidHrp`](crate::EncodeError::InvalidHrp) error for `input`
+    /// (eg: if the length of a Z85 `input` isn't a multiple of 4).
+    ///
+    /// If the `"alloc"` feature isn't enabled,
+    /// this also panics when the Base58 string can be longer than 512 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::Config;
+    ///
+    /// assert!(Config::B64.encode_iter(b"hi").eq(*b"aGk="));
+    /// assert!(Config::B64.end_padding(false).encode_iter(b"hi").eq(*b"aGk"));
+    /// assert!(Config::B58.encode_iter(b"hi").eq(*b"8wr"));
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn encode_iter(self, input: &[u8]) -> EncodeIter<'_> {
+        if let Err(e) = StreamEncoder::check_input(input.len(), self) {
+            e.panic()
+        }
+
+        let mut encoder = StreamEncoder::new(self);
+        encoder.end_input();
+
+        EncodeIter {
+            input,
+            encoder,
+            remaining: encoded_len(input.len(), self),
+            exact: !matches!(self.encoding, Encoding::Base58(_)),
+        }
+    }
+
+    /// Returns an iterator over the characters of `input` encoded
+    /// with the encoding determined by this `Config`.
+    ///
+    /// This is [`encode_iter`](Self::encode_iter) yielding `char`s instead of bytes.
+    ///
+    /// # Panics
+    ///
+    /// This panics in the same situations as [`encode_iter`](Self::encode_iter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::Config;
+    ///
+    /// let mut string = String::from("0x");
+    /// string.extend(Config::HEX_LOWER.encode_char_iter(&[0xBE, 0xEF]));
+    ///
+    /// assert_eq!(string, "0xbeef");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn encode_char_iter(self, input: &[u8]) -> EncodeCharIter<'_> {
+        EncodeCharIter(self.encode_iter(input))
+    }
+
+    /// Returns an iterator over the bytes decoded from `input`
+    /// with the encoding determined by this `Config`.
+    ///
+    /// The configurations that [`Decoder`] supports (listed in the [`Encoder`] docs)
+    /// are decoded in small chunks as the iterator is iterated over.
+    /// Every other configuration is decoded all at once on the first call to `next`,
+    /// into a `Vec` if the `"alloc"` feature is enabled,
+    /// otherwise into a 512 byte buffer in the iterator.
+    ///
+    /// # Errors
+    ///
+    /// The iterator yields the first error that [`decode`](crate::decode()) returns,
+    /// other than [`DecodeError::WrongOutputLength`].
+    ///
+    /// For the configurations that are decoded in chunks,
+    /// errors about the padding or the length of `input` are yielded before any byte,
+    /// errors about specific characters (eg: [`DecodeError::InvalidByte`])
+    /// are yielded after the bytes decoded from the characters before them,
+    /// and [`DecodeError::ExcessBits`] is yielded after all the bytes.
+    ///
+    /// For the configurations that are decoded all at once,
+    /// errors are yielded before any byte,
+    /// and if the `"alloc"` feature isn't enabled,
+    /// this yields a [`DecodeError::WrongOutputLength`] error
+    /// when the decoded bytes can be longer than 512 bytes.
+    ///
+    /// Iteration ends after the first error.
+    ///
+    /// [`Encoder`]: crate::Encoder
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_base::{Config, DecodeError};
+    ///
+    /// assert!(Config::B64.decode_iter(b"aGk=").eq([Ok(b'h'), Ok(b'i')]));
+    /// assert!(Config::B58.decode_iter(b"8wr").eq([Ok(b'h'), Ok(b'i')]));
+    ///
+    /// // the last character has bits that aren't part of any byte
+    /// let mut iter = Config::B64.decode_iter(b"aGl=");
+    /// assert_eq!(iter.next(), Some(Ok(b'h')));
+    /// assert_eq!(iter.next(), Some(Ok(b'i')));
+    /// assert!(matches!(iter.next(), Some(Err(DecodeError::ExcessBits(_)))));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// ```
+    pub const fn decode_iter(self, input: &[u8]) -> DecodeIter<'_> {
+        let source = if crate::incremental::is_supported(self) {
+            DecodeSource::Pieces {
+                decoder: Decoder::new(self),
+                buffer: [0; DECODE_PIECE_LEN],
+                checked: false,
+            }
+        } else {
+            DecodeSource::Whole {
+                config: self,
+                buffer: EMPTY_WHOLE_BUFFER,
+            }
+        };
+
+        DecodeIter {
+            input,
+            source,
+            pos: 0,
+            len: 0,
+            finished: false,
+        }
+    }
+}
+
+/// Iterator over the bytes of an encoded string, returned by [`Config::encode_iter`].
+#[derive(Clone)]
+pub struct EncodeIter<'a> {
+    // the input that hasn't been encoded yet
+    input: &'a [u8],
+    encoder: StreamEncoder,
+    // the amount of bytes that are left to be yielded,
+    // which is only an upper bound until `exact` is true
+    remaining: usize,
+    exact: bool,
+}
+
+impl Iterator for EncodeIter<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.encoder.next_byte(&mut self.input)?;
+
+        // base 58 strings are encoded all at once before the first byte is returned
+        if !self.exact {
+            self.remaining = self.encoder.whole_encoded_len().unwrap_or(self.remaining);
+            self.exact = true;
+        }
+
+        self.remaining -= 1;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = if self.exact { self.remaining } else { 0 };
+        (lower, Some(self.remaining))
+    }
+}
+
+impl FusedIterator for EncodeIter<'_> {}
+
+/// Iterator over the characters of an encoded string,
+/// returned by [`Config::encode_char_iter`].
+#[derive(Clone)]
+pub struct EncodeCharIter<'a>(EncodeIter<'a>);
+
+impl Iterator for EncodeCharIter<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next().map(char::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl FusedIterator for EncodeCharIter<'_> {}
+
+/// Iterator over the bytes decoded from a string, returned by [`Config::decode_iter`].
+#[derive(Clone)]
+pub struct DecodeIter<'a> {
+    // the input that hasn't been decoded yet
+    input: &'a [u8],
+    source: DecodeSource,
+    // the decoded bytes that haven't been yielded yet are `buffer[pos..len]`
+    pos: usize,
+    len: usize,
+    finished: bool,
+}
+
+// `Decoder` is large because of its lookup table,
+// boxing it would require the `"alloc"` feature.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum DecodeSource {
+    // the configurations that `Decoder` supports,
+    // `checked` is whether the padding and length of the input were checked
+    Pieces {
+        decoder: Decoder,
+        buffer: [u8; DECODE_PIECE_LEN],
+        checked: bool,
+    },
+    // every other configuration, which is decoded all at once
+    Whole {
+        config: Config,
+        buffer: WholeBuffer,
+    },
+}
+
+impl Iterator for DecodeIter<'_> {
+    type Item = Result<u8, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos == self.len {
+            if self.finished {
+                return None;
+            }
+
+            self.pos = 0;
+            self.len = 0;
+
+            let res = match &mut self.source {
+                DecodeSource::Pieces {
+                    decoder, checked, ..
+                } if !*checked => {
+                    *checked = true;
+                    decoder.check_input(self.input)
+                }
+                DecodeSource::Pieces { decoder, .. } if self.input.is_empty() => {
+                    self.finished = true;
+                    decoder.finish()
+                }
+                DecodeSource::Pieces {
+                    decoder, buffer, ..
+                } => {
+                    let split = self.input.len().min(DECODE_PIECE_LEN);
+                    let (piece, rem) = self.input.split_at(split);
+                    self.input = rem;
+                    decoder.feed(piece, buffer).map(|len| self.len = len)
+                }
+                DecodeSource::Whole { config, buffer } => {
+                    let out = whole_slice(buffer, decoded_len(self.input, *config));
+                    let res = decode_to_slice(self.input, out, *config);
+                    self.input = &[];
+                    self.finished = true;
+                    res.map(|len| self.len = len)
+                }
+            };
+
+            if let Err(e) = res {
+                self.finished = true;
+                return Some(Err(e));
+            }
+        }
+
+        let byte = match &self.source {
+            DecodeSource::Pieces { buffer, .. } => buffer[self.pos],
+            DecodeSource::Whole { buffer, .. } => buffer[self.pos],
+        };
+        self.pos += 1;
+        Some(Ok(byte))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.len - self.pos;
+        let errors = usize::from(!self.finished);
+        let undecoded = match &self.source {
+            DecodeSource::Pieces { decoder, .. } => decoder.feed_len(self.input.len()),
+            DecodeSource::Whole { config, .. } => decoded_len(self.input, *config),
+        };
+        (buffered, Some(buffered + undecoded + errors))
+    }
+}
+
+impl FusedIterator for DecodeIter<'_> {}
@@ -1,4 +1,4 @@
-//! For decoding/encoding base 64/32/16 strings at compile-time.
+//! For decoding/encoding base 85/64/58/45/32/16 and bech32 strings at compile-time.
 //!
 //! # Examples
 //!
@@ -45,18 +45,69 @@
 //! }
 //! ```
 //!
+//! ### Runtime
+//!
+//! The `"alloc"` feature enables methods for encoding and decoding into
+//! heap-allocated buffers at runtime.
+//!
+#![cfg_attr(feature = "alloc", doc = "```rust")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+//! use const_base::Config;
+//!
+//! let encoded: String = Config::B64.encode_to_string(b"hello").unwrap();
+//! assert_eq!(encoded, "aGVsbG8=");
+//!
+//! let decoded: Vec<u8> = Config::B64.decode_to_vec(encoded.as_bytes()).unwrap();
+//! assert_eq!(decoded, b"hello");
+//! ```
+//!
+//! # Cargo features
+//!
+//! - `"alloc"`: enables the [`Config`] methods that encode into a `String`
+//! and decode into a `Vec<u8>`, like
+#![cfg_attr(
+    feature = "alloc",
+    doc = "[`encode_to_string`](Config::encode_to_string) and",
+    doc = "[`decode_to_vec`](Config::decode_to_vec)."
+)]
+#![cfg_attr(
+    not(feature = "alloc"),
+    doc = "`encode_to_string` and `decode_to_vec`."
+)]
+//!
+#![cfg_attr(
+    feature = "std",
+    doc = "- `\"std\"`: enables the [`EncoderWriter`] and [`DecoderReader`] types,"
+)]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "- `\"std\"`: enables the `EncoderWriter` and `DecoderReader` types,"
+)]
+//! for encoding and decoding streams with `std::io::{Write, Read}`.
+//! Also enables the `"alloc"` feature.
+//!
 //! # No-std support
 //!
 //! `const_base` is `#![no_std]`, it can be used anywhere Rust can be used.
+//! The `"alloc"` feature requires the `alloc` crate,
+//! and the `"std"` feature requires the standard library.
 //!
 //! # Minimum Supported Rust Version
 //!
-//! `const_base` requires Rust 1.64.0.
+//! `const_base` requires Rust 1.83.0,
+//! because its `const fn`s write into mutable slices.
+//! `const_base` 0.2 supports Rust 1.64.0.
 //!
 //!
 #![no_std]
 #![deny(clippy::missing_const_for_fn)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 #[macro_use]
 mod codec_macros;
 
@@ -75,13 +126,38 @@ mod base_32;
 
 mod base_64;
 
+mod base_58;
+
+mod base_45;
+
+mod base_85;
+
+mod bech32;
+
+mod sha256;
+
 mod encode_decode_shared;
 
+mod incremental;
+
+mod encoded;
+
+mod streaming;
+
+mod iter;
+
+#[cfg(feature = "alloc")]
+mod encode_decode_alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
 mod macros;
 
 pub mod utils;
 
 #[cfg(test)]
+#[macro_use]
 mod test_utils;
 
 #[doc(hidden)]
@@ -94,12 +170,24 @@ pub mod __priv_utils;
 
 pub use crate::{
     array_str::ArrayStr,
-    config::Config,
+    config::{BitOrder, Config, LineEnding, PaddingMode},
     encode_decode_shared::*,
-    encoding::{B32CharSet, B64CharSet, Encoding, HexCharSet},
-    errors::{DecodeError, ExcessBits, InvalidByte, WrongInputLength, WrongOutputLength},
+    encoded::Encoded,
+    encoding::{
+        B32CharSet, B58CharSet, B64CharSet, B85CharSet, Bech32Variant, CustomCharSet, Encoding,
+        HexCharSet,
+    },
+    errors::{
+        ChecksumMismatch, ChunkOverflow, DecodeError, EncodeError, ExcessBits, InvalidByte,
+        InvalidHrp, InvalidPadding, WrongCheckSymbol, WrongInputLength, WrongOutputLength,
+    },
+    incremental::{Decoder, Encoder},
+    iter::{DecodeIter, EncodeCharIter, EncodeIter},
 };
 
+#[cfg(feature = "std")]
+pub use crate::io::{DecoderReader, EncoderWriter};
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,126 @@
+// A const-evaluable implementation of the SHA-256 hash function,
+// used for the checksum of the Base58Check encoding.
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const BLOCK_LEN: usize = 64;
+
+pub(crate) const fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut state = INITIAL_STATE;
+
+    let full_blocks = input.len() / BLOCK_LEN;
+    for_range! {block_i in 0..full_blocks =>
+        let mut block = [0u8; BLOCK_LEN];
+        for_range! {i in 0..BLOCK_LEN =>
+            block[i] = input[block_i * BLOCK_LEN + i];
+        }
+        state = compress(state, &block);
+    }
+
+    // the remaining bytes, followed by the `0x80` byte,
+    // zeroes, and the bit length of the input as a big-endian u64.
+    let rem_len = input.len() % BLOCK_LEN;
+    let mut last_blocks = [0u8; BLOCK_LEN * 2];
+    for_range! {i in 0..rem_len =>
+        last_blocks[i] = input[full_blocks * BLOCK_LEN + i];
+    }
+    last_blocks[rem_len] = 0x80;
+
+    let last_blocks_len = if rem_len + 9 > BLOCK_LEN {
+        BLOCK_LEN * 2
+    } else {
+        BLOCK_LEN
+    };
+
+    let bit_len = (input.len() as u64).wrapping_mul(8).to_be_bytes();
+    for_range! {i in 0..8 =>
+        last_blocks[last_blocks_len - 8 + i] = bit_len[i];
+    }
+
+    for_range! {block_i in 0..last_blocks_len / BLOCK_LEN =>
+        let mut block = [0u8; BLOCK_LEN];
+        for_range! {i in 0..BLOCK_LEN =>
+            block[i] = last_blocks[block_i * BLOCK_LEN + i];
+        }
+        state = compress(state, &block);
+    }
+
+    let mut out = [0u8; 32];
+    for_range! {i in 0..8 =>
+        let word = state[i].to_be_bytes();
+        for_range! {j in 0..4 =>
+            out[i * 4 + j] = word[j];
+        }
+    }
+    out
+}
+
+// SHA-256 applied twice, as used by Bitcoin.
+pub(crate) const fn double_sha256(input: &[u8]) -> [u8; 32] {
+    sha256(&sha256(input))
+}
+
+const fn compress(mut state: [u32; 8], block: &[u8; BLOCK_LEN]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+
+    for_range! {i in 0..16 =>
+        w[i] = u32::from_be_bytes([
+            block[i * 4],
+            block[i * 4 + 1],
+            block[i * 4 + 2],
+            block[i * 4 + 3],
+        ]);
+    }
+
+    for_range! {i in 16..64 =>
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+    for_range! {i in 0..64 =>
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    let working = [a, b, c, d, e, f, g, h];
+    for_range! {i in 0..8 =>
+        state[i] = state[i].wrapping_add(working[i]);
+    }
+    state
+}
@@ -0,0 +1,465 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "alloc"))]
+use crate::WrongOutputLength;
+
+use crate::{
+    encode_decode_shared::break_before, B85CharSet, Config, EncodeError, Encoder, Encoding,
+};
+
+// The amount of bytes that are encoded into `StreamEncoder::chars` at once,
+// which are at most 12 characters long once encoded (2 hexadecimal digits per byte).
+const PIECE_LEN: usize = 6;
+
+// The capacity of `StreamEncoder::chars`.
+const CHARS_LEN: usize = 16;
+
+// The length of the buffer that the configurations which can't be encoded
+// (or decoded) in pieces are encoded (or decoded) into,
+// if the `"alloc"` feature isn't enabled.
+// `Config::display` always encodes them into a buffer of this length.
+pub(crate) const WHOLE_LEN: usize = 512;
+
+// The buffer that the configurations which can't be encoded (or decoded) in pieces
+// are encoded (or decoded) into all at once.
+#[cfg(feature = "alloc")]
+pub(crate) type WholeBuffer = Vec<u8>;
+
+#[cfg(not(feature = "alloc"))]
+pub(crate) type WholeBuffer = [u8; WHOLE_LEN];
+
+#[cfg(feature = "alloc")]
+pub(crate) const EMPTY_WHOLE_BUFFER: WholeBuffer = Vec::new();
+
+#[cfg(not(feature = "alloc"))]
+pub(crate) const EMPTY_WHOLE_BUFFER: WholeBuffer = [0; WHOLE_LEN];
+
+// Gets a slice of `buffer` that's at least `len` bytes long if possible,
+// only the allocated buffer can grow to fit `len` bytes.
+pub(crate) fn whole_slice(buffer: &mut WholeBuffer, len: usize) -> &mut [u8] {
+    #[cfg(feature = "alloc")]
+    buffer.resize(len, 0);
+
+    #[cfg(not(feature = "alloc"))]
+    let _ = len;
+
+    &mut buffer[..]
+}
+
+// Encodes its input one byte at a time, for any `Config`.
+//
+// The input is passed to every call that encodes more bytes,
+// so that it can be passed in pieces, the encoded string is only finished
+// after `end_input` is called.
+//
+// The characters are encoded in small pieces, then laid out into lines and groups,
+// except for base 58 and bech32, which are encoded into a `WholeBuffer` all at once,
+// and so must be passed the entire input after `end_input` is called.
+#[derive(Clone)]
+pub(crate) struct StreamEncoder {
+    config: Config,
+    // the config that the characters are encoded with,
+    // without the lines, groups, delimiters, or check symbol that are added here.
+    raw_config: Config,
+    source: Source,
+    // whether all of the input was passed to the encoder
+    input_ended: bool,
+    stage: Stage,
+    // the encoded characters that haven't been laid out yet are `chars[chars_pos..chars_len]`
+    chars: [u8; CHARS_LEN],
+    chars_pos: usize,
+    chars_len: usize,
+    // the amount of characters that were laid out
+    laid_out: usize,
+    // the amount of bytes of the line ending or group separator before the next character
+    // that were already returned
+    break_pos: usize,
+}
+
+// `Encoder` is large because of its lookup tables,
+// boxing it would require the `"alloc"` feature.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum Source {
+    // base 64, base 32, and hexadecimal without prefixes or separators,
+    // `checksum` is the Crockford checksum of the characters encoded so far
+    Bits {
+        encoder: Encoder,
+        finished: bool,
+        checksum: u8,
+    },
+    // base 45 and base 85, where every `chunk` bytes are encoded independently,
+    // `pending[..pending_len]` is the partial chunk from the previous inputs
+    Chunks {
+        chunk: usize,
+        pending: [u8; 4],
+        pending_len: usize,
+    },
+    // hexadecimal with prefixes or separators,
+    // `offset` is the index of the next character in the characters that encode the next byte
+    FormattedHex {
+        digits: [u8; 16],
+        offset: usize,
+        first: bool,
+    },
+    // base 58 and bech32, where every character depends on the entire input
+    Whole {
+        buffer: WholeBuffer,
+        encoded: bool,
+        pos: usize,
+        len: usize,
+    },
+}
+
+#[derive(Copy, Clone)]
+enum Stage {
+    // the index of the next byte of the prefix
+    Prefix(usize),
+    Body,
+    // the index of the next byte of the suffix
+    Suffix(usize),
+}
+
+// Whether the config is encoded all at once into a `WholeBuffer`.
+pub(crate) const fn encodes_whole(config: Config) -> bool {
+    matches!(config.encoding, Encoding::Base58(_) | Encoding::Bech32(_))
+}
+
+impl StreamEncoder {
+    // Returns the errors that `encode_to_slice` returns for an input of `input_len` bytes
+    // regardless of the length of the output,
+    // and a `WrongOutputLength` error if the encoded string can't fit in a `WholeBuffer`
+    // (bech32 strings are at most 90 characters long, so they always fit).
+    pub(crate) const fn check_input(input_len: usize, config: Config) -> Result<(), EncodeError> {
+        let res = match config.encoding {
+            Encoding::Base85(cset) => crate::base_85::check_encode_input(input_len, config, cset),
+            Encoding::Bech32(_) => crate::bech32::check_encode_input(input_len, config),
+            _ => Ok(()),
+        };
+
+        #[cfg(not(feature = "alloc"))]
+        if let Encoding::Base58(_) = config.encoding {
+            let expected = crate::encoded_len(input_len, raw_config(config));
+            if expected > WHOLE_LEN {
+                return Err(EncodeError::WrongOutputLength(WrongOutputLength {
+                    expected,
+                    found: WHOLE_LEN,
+                }));
+            }
+        }
+
+        res
+    }
+
+    // Constructs a `StreamEncoder`,
+    // the input must not cause `check_input` to return an error.
+    pub(crate) const fn new(config: Config) -> Self {
+        let raw_config = raw_config(config);
+
+        let source = match config.encoding {
+            _ if encodes_whole(config) => Source::Whole {
+                buffer: EMPTY_WHOLE_BUFFER,
+                encoded: false,
+                pos: 0,
+                len: 0,
+            },
+            _ if crate::incremental::is_supported(raw_config) => Source::Bits {
+                encoder: Encoder::new(raw_config),
+                finished: false,
+                checksum: 0,
+            },
+            Encoding::Hex(cset) => Source::FormattedHex {
+                digits: cset.lookup().into_enc,
+                offset: 0,
+                first: true,
+            },
+            Encoding::Base45 => Source::Chunks {
+                chunk: 2,
+                pending: [0; 4],
+                pending_len: 0,
+            },
+            _ => Source::Chunks {
+                chunk: 4,
+                pending: [0; 4],
+                pending_len: 0,
+            },
+        };
+
+        Self {
+            config,
+            raw_config,
+            source,
+            input_ended: false,
+            stage: Stage::Prefix(0),
+            chars: [0; CHARS_LEN],
+            chars_pos: 0,
+            chars_len: 0,
+            laid_out: 0,
+            break_pos: 0,
+        }
+    }
+
+    // The length of the encoded string once the input was encoded into the `WholeBuffer`,
+    // only valid for base 58, which doesn't have a prefix.
+    pub(crate) const fn whole_encoded_len(&self) -> Option<usize> {
+        match self.source {
+            Source::Whole {
+                encoded: true, len, ..
+            } => Some(crate::encode_decode_shared::laid_out_len(len, self.config)),
+            _ => None,
+        }
+    }
+
+    // Marks the end of the input, after which the encoded string is finished.
+    pub(crate) const fn end_input(&mut self) {
+        self.input_ended = true;
+    }
+
+    // Returns the next byte of the encoded string, encoding the start of `input`.
+    //
+    // This returns `None` at the end of the encoded string,
+    // or when `input` is empty before `end_input` is called.
+    pub(crate) fn next_byte(&mut self, input: &mut &[u8]) -> Option<u8> {
+        loop {
+            match self.stage {
+                Stage::Prefix(i) => {
+                    let (first, second) = prefix(&self.config);
+                    let byte = match i.checked_sub(first.len()) {
+                        None => first.get(i),
+                        Some(i) => second.get(i),
+                    };
+
+                    if let Some(&b) = byte {
+                        self.stage = Stage::Prefix(i + 1);
+                        return Some(b);
+                    }
+                    self.stage = Stage::Body;
+                }
+                Stage::Body => {
+                    if self.chars_pos == self.chars_len {
+                        let input_len = input.len();
+                        self.chars_len = self.encode_chars(input);
+                        self.chars_pos = 0;
+
+                        if self.chars_len == 0 {
+                            if input.len() != input_len {
+                                continue;
+                            } else if !self.input_ended {
+                                return None;
+                            }
+                            self.stage = Stage::Suffix(0);
+                            continue;
+                        }
+                    }
+
+                    let brk = break_before(self.laid_out, &self.config);
+                    if let Some(&b) = brk.get(self.break_pos) {
+                        self.break_pos += 1;
+                        return Some(b);
+                    }
+
+                    let c = self.chars[self.chars_pos];
+                    self.chars_pos += 1;
+                    self.laid_out += 1;
+                    self.break_pos = 0;
+                    return Some(c);
+                }
+                Stage::Suffix(i) => {
+                    let byte = suffix(&self.config).get(i).copied();
+                    if byte.is_some() {
+                        self.stage = Stage::Suffix(i + 1);
+                    }
+                    return byte;
+                }
+            }
+        }
+    }
+
+    // Writes the next bytes of the encoded string into `out`, encoding the start of `input`,
+    // returning the amount of written bytes, which is only less than `out.len()`
+    // at the end, or when all of `input` was encoded before `end_input` is called.
+    pub(crate) fn read(&mut self, input: &mut &[u8], out: &mut [u8]) -> usize {
+        let mut len = 0;
+        while len != out.len() {
+            match self.next_byte(input) {
+                Some(b) => out[len] = b,
+                None => break,
+            }
+            len += 1;
+        }
+        len
+    }
+
+    // Encodes the next characters into `self.chars`, before they're laid out,
+    // returning how many there are.
+    //
+    // This only returns zero without consuming any of `input` at the end,
+    // or when `input` is empty before `end_input` is called.
+    fn encode_chars(&mut self, input: &mut &[u8]) -> usize {
+        let out = &mut self.chars;
+
+        match &mut self.source {
+            Source::Bits {
+                encoder,
+                finished,
+                checksum,
+            } => {
+                let mut len = if !input.is_empty() {
+                    let (piece, rem) = input.split_at(input.len().min(PIECE_LEN));
+                    *input = rem;
+                    encoder.feed(piece, out)
+                } else if self.input_ended && !*finished {
+                    *finished = true;
+                    encoder.finish(out)
+                } else {
+                    return 0;
+                };
+
+                if let Encoding::Base32(cset) = self.config.encoding {
+                    if self.config.has_check_symbol() {
+                        let from_enc = &cset.lookup().from_enc;
+                        *checksum = crate::base_32::continue_crockford_checksum(
+                            *checksum,
+                            &out[..len],
+                            from_enc,
+                        );
+
+                        if *finished {
+                            out[len] = crate::base_32::crockford_check_symbol(*checksum);
+                            len += 1;
+                        }
+                    }
+                }
+
+                len
+            }
+            Source::Chunks {
+                chunk,
+                pending,
+                pending_len,
+            } => {
+                while let (true, [b, rem @ ..]) = (*pending_len != *chunk, *input) {
+                    pending[*pending_len] = *b;
+                    *pending_len += 1;
+                    *input = rem;
+                }
+
+                // the last chunk can be partial
+                if *pending_len == *chunk || (self.input_ended && *pending_len != 0) {
+                    let piece = &pending[..*pending_len];
+                    *pending_len = 0;
+
+                    // the errors were returned by `check_input`
+                    crate::EncodeError::unwrap(crate::encode_to_slice(piece, out, self.raw_config))
+                } else {
+                    0
+                }
+            }
+            Source::FormattedHex {
+                digits,
+                offset,
+                first,
+            } => {
+                let byte_prefix = self.config.hex_byte_prefix.as_bytes();
+
+                let mut len = 0;
+                while let (Some(&b), true) = (input.first(), len != out.len()) {
+                    // the separator goes before every byte after the first
+                    let separator = if *first {
+                        &[][..]
+                    } else {
+                        self.config.hex_byte_separator.as_bytes()
+                    };
+                    let literals_len = separator.len() + byte_prefix.len();
+
+                    let (high, low) = (b >> 4, b & 0xF);
+                    let (first_digit, second_digit) = if self.config.is_lsb_first() {
+                        (low, high)
+                    } else {
+                        (high, low)
+                    };
+
+                    out[len] = match offset.checked_sub(separator.len()) {
+                        None => separator[*offset],
+                        Some(i) if i < byte_prefix.len() => byte_prefix[i],
+                        Some(_) if *offset == literals_len => digits[first_digit as usize],
+                        Some(_) => digits[second_digit as usize],
+                    };
+                    len += 1;
+                    *offset += 1;
+
+                    if *offset == literals_len + 2 {
+                        *input = &input[1..];
+                        *offset = 0;
+                        *first = false;
+                    }
+                }
+
+                len
+            }
+            Source::Whole {
+                buffer,
+                encoded,
+                pos,
+                len,
+            } => {
+                if !*encoded {
+                    if !self.input_ended {
+                        return 0;
+                    }
+
+                    let max_len = crate::encoded_len(input.len(), self.raw_config);
+                    let whole = whole_slice(buffer, max_len);
+
+                    // the errors were returned by `check_input`
+                    *len = crate::EncodeError::unwrap(crate::encode_to_slice(
+                        input,
+                        whole,
+                        self.raw_config,
+                    ));
+                    // the human-readable part of bech32 strings is written as a prefix
+                    let (first, second) = prefix(&self.config);
+                    *pos = first.len() + second.len();
+                    *encoded = true;
+                    *input = &[];
+                }
+
+                let piece = (*len - *pos).min(out.len());
+                out[..piece].copy_from_slice(&buffer[*pos..*pos + piece]);
+                *pos += piece;
+                piece
+            }
+        }
+    }
+}
+
+// The config without the layout, delimiters, and check symbol,
+// which are added by `StreamEncoder`.
+const fn raw_config(mut config: Config) -> Config {
+    config.line_width = 0;
+    config.group_size = 0;
+    config.delimiters = false;
+    config.check_symbol = false;
+    config
+}
+
+// The bytes before the laid out characters,
+// split in two because bech32 strings start with the human-readable part followed by a `1`.
+const fn prefix(config: &Config) -> (&'static [u8], &'static [u8]) {
+    match config.encoding {
+        Encoding::Hex(_) => (config.hex_prefix.as_bytes(), &[]),
+        Encoding::Base85(B85CharSet::Ascii85) if config.has_delimiters() => (b"<~", &[]),
+        Encoding::Bech32(_) => (config.hrp.as_bytes(), b"1"),
+        _ => (&[], &[]),
+    }
+}
+
+// The bytes after the laid out characters.
+const fn suffix(config: &Config) -> &'static [u8] {
+    if config.has_delimiters() {
+        b"~>"
+    } else {
+        &[]
+    }
+}
@@ -1,3 +1,45 @@
+use crate::ArrayStr;
+
+pub(crate) trait AsBytes {
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl<const N: usize> AsBytes for ArrayStr<N> {
+    fn as_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> AsBytes for [u8; N] {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+// Calls the const-generic `$function` with `$len` as the `OUT` argument,
+// returning the result as a slice stored in `$buffer`.
+//
+// Requires the `AsBytes` trait to be in scope.
+macro_rules! call_with_len {
+    ($buffer:ident, $len:expr, $function:ident $args:tt) => {
+        call_with_len! {
+            @inner $buffer, $len, $function $args,
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29
+            30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56
+            57 58 59 60 61 62 63 64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+        }
+    };
+    (@inner $buffer:ident, $len:expr, $function:ident $args:tt, $($n:literal)*) => {
+        match $len {
+            $($n => $function::<$n> $args.map(|x| {
+                $buffer[..$n].copy_from_slice(x.as_bytes());
+                &$buffer[..$n]
+            }),)*
+            _ => unreachable!(),
+        }
+    };
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct ByteSet(pub(crate) [bool; 256]);
 
@@ -1,9 +1,47 @@
+#[cfg(feature = "alloc")]
+mod alloc_tests;
+
 mod array_str_tests;
 
 mod base16_tests;
 
 mod base32_tests;
 
+mod base45_tests;
+
+mod base58_tests;
+
 mod base64_tests;
 
+mod base85_tests;
+
+mod bech32_tests;
+
+mod bit_order_tests;
+
+mod custom_charset_tests;
+
+mod display_tests;
+
+mod group_tests;
+
+mod hex_format_tests;
+
+mod ignore_bytes_tests;
+
+mod incremental_tests;
+
+#[cfg(feature = "std")]
+mod io_tests;
+
+mod iter_tests;
+
+mod line_wrap_tests;
+
+mod padding_tests;
+
+mod sha256_tests;
+
+mod slice_tests;
+
 mod ui_tests;
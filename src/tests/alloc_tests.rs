@@ -0,0 +1,149 @@
+use crate::{
+    decode, encode, encoded_len_of, test_utils::AsBytes, Config, DecodeError, EncodeError,
+    LineEnding,
+};
+
+use alloc::{string::String, vec};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+#[test]
+fn test_alloc_matches_const() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for cfg in [
+        Config::B64,
+        Config::B64_URL_SAFE.end_padding(false),
+        Config::B32,
+        Config::B32_CROCKFORD.check_symbol(true),
+        Config::HEX,
+        Config::HEX_LOWER.hex_prefix("0x"),
+        Config::B58,
+        Config::B58.checksum(true),
+        Config::B45,
+        Config::ASCII85.delimiters(true),
+        Config::Z85,
+        Config::bech32("bc"),
+        Config::B64.line_wrap(4, LineEnding::CrLf),
+    ] {
+        for in_length in 0..=16 {
+            if matches!(
+                cfg.encoding,
+                crate::Encoding::Base85(crate::B85CharSet::Z85)
+            ) && in_length % 4 != 0
+            {
+                continue;
+            }
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 16];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+                if rng.gen_bool(0.2) {
+                    input[..in_length / 2].fill(0);
+                }
+
+                let enc_len = encoded_len_of(input, cfg);
+                let mut buffer = [0u8; 80];
+                let expected = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                let encoded = cfg.encode_to_string(input).unwrap();
+                assert_eq!(encoded.as_bytes(), expected, "{:?}", cfg.encoding);
+
+                let mut appended = String::from("prefix");
+                assert_eq!(cfg.encode_into_string(input, &mut appended), Ok(enc_len));
+                assert_eq!(&appended[..6], "prefix");
+                assert_eq!(&appended.as_bytes()[6..], expected);
+
+                let mut buffer = [0u8; 80];
+                let expected = call_with_len!(buffer, in_length, decode(expected, cfg)).unwrap();
+
+                assert_eq!(
+                    cfg.decode_to_vec(encoded.as_bytes()).unwrap(),
+                    expected,
+                    "{:?}",
+                    cfg.encoding
+                );
+
+                let mut appended = b"prefix".to_vec();
+                assert_eq!(
+                    cfg.decode_into_vec(encoded.as_bytes(), &mut appended),
+                    Ok(in_length)
+                );
+                assert_eq!(&appended[..6], b"prefix");
+                assert_eq!(&appended[6..], expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_alloc_errors() {
+    for (cfg, input) in [
+        (Config::B64, &b"Zm9!"[..]),
+        (Config::B32, b"MZX"),
+        (Config::HEX, b"F0F"),
+        (Config::B58.checksum(true), b"3vQB7B6MrGQZaxCvV"),
+        (Config::B45, b"GGW"),
+        (Config::ASCII85, b"s8W-\""),
+        (Config::bech32("a"), b"a12uel5m"),
+    ] {
+        let mut out = [0u8; 40];
+        let expected = crate::decode_to_slice(input, &mut out, cfg).unwrap_err();
+
+        assert_eq!(cfg.decode_to_vec(input), Err(expected));
+
+        // the buffer is left unchanged on error
+        let mut appended = b"prefix".to_vec();
+        assert!(cfg.decode_into_vec(input, &mut appended).is_err());
+        assert_eq!(appended, b"prefix");
+    }
+
+    assert!(matches!(
+        Config::B64.decode_to_vec(b"AAAAA"),
+        Err(DecodeError::WrongInputLength(_))
+    ));
+}
+
+#[test]
+fn test_alloc_long_inputs() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    let mut input = vec![0u8; if cfg!(miri) { 100 } else { 2000 }];
+    rng.fill(&mut input[10..]);
+
+    // base 58 supports inputs longer than what `encoded_len_of` and `decoded_len` support
+    for cfg in [
+        Config::B64,
+        Config::B32,
+        Config::HEX,
+        Config::B58,
+        Config::B58.checksum(true),
+        Config::ASCII85,
+    ] {
+        let encoded = cfg.encode_to_string(&input).unwrap();
+        assert_eq!(cfg.decode_to_vec(encoded.as_bytes()).unwrap(), input);
+    }
+
+    // bech32 strings are limited to 90 characters
+    let mut out = String::from("prefix");
+    assert!(matches!(
+        Config::bech32m("long").encode_into_string(&input, &mut out),
+        Err(EncodeError::WrongInputLength(x)) if x.length() == input.len()
+    ));
+    assert_eq!(out, "prefix");
+
+    let encoded = Config::B64.encode_to_string(&input).unwrap();
+    assert_eq!(
+        encoded.as_bytes(),
+        data_encoding::BASE64.encode(&input).as_bytes()
+    );
+
+    let encoded = Config::B58.encode_to_string(&input).unwrap();
+    let mut expected = [0u8; 4000];
+    let len = bs58::encode(&input).into(&mut expected[..]).unwrap();
+    assert_eq!(encoded.as_bytes(), &expected[..len]);
+}
@@ -1,4 +1,4 @@
-use crate::{Config, DecodeError};
+use crate::{Config, DecodeError, EncodeError};
 
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -52,12 +52,28 @@ fn test_encode_decode() {
 fn test_encode_hex_errors() {
     {
         let err = Config::HEX.encode::<3>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 3 && err.found() == 4, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 3 && x.found() == 4
+            ),
+            "{:?}",
+            err
+        );
     }
     assert_eq!(Config::HEX.encode::<4>(&[0xAB, 0xCD]).unwrap(), "ABCD");
     {
         let err = Config::HEX.encode::<5>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 5 && err.found() == 4, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 5 && x.found() == 4
+            ),
+            "{:?}",
+            err
+        );
     }
 }
 
@@ -72,7 +88,7 @@ fn test_decode_hex_errors() {
 
     // InvalidByte
     for (cfg, (b, is_invalid)) in invalid_bytes_iters {
-        let mut bytes = *b"00\0000";
+        let mut bytes = *b"00\x00000";
         bytes[2] = b;
         let res = cfg.decode::<3>(&bytes);
 
@@ -154,3 +170,39 @@ fn test_decode_hex_errors() {
         );
     }
 }
+
+#[test]
+fn test_decode_hex_strict_case() {
+    for (cfg, valid, other_case) in [
+        (Config::HEX_LOWER, b"0123456789abcdef", b"ABCDEF"),
+        (Config::HEX, b"0123456789ABCDEF", b"abcdef"),
+    ] {
+        // without strict case, both cases are accepted
+        assert!(cfg.decode::<8>(valid).is_ok());
+
+        let cfg = cfg.strict_case(true);
+        assert!(cfg.decode::<8>(valid).is_ok());
+
+        for (i, &b) in other_case.iter().enumerate() {
+            let mut input = *valid;
+            let index = 10 + i;
+            input[index] = b;
+
+            let err = cfg.decode::<8>(&input).unwrap_err();
+            assert!(
+                matches!(&err, DecodeError::InvalidByte(x) if x.index() == index && x.byte() == b),
+                "{:?}",
+                err
+            );
+        }
+    }
+
+    // works along with skipped bytes
+    let cfg = Config::HEX_LOWER.strict_case(true).group(2, b':');
+    assert_eq!(cfg.decode::<2>(b"ca:fe").unwrap(), [0xCA, 0xFE]);
+    assert!(cfg.decode::<2>(b"ca:Fe").is_err());
+
+    // other encodings ignore strict case
+    let cfg = Config::B32_CROCKFORD.strict_case(true);
+    assert_eq!(cfg.decode::<5>(b"d1jprv3f").unwrap(), *b"hello");
+}
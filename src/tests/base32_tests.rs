@@ -1,6 +1,6 @@
 use crate::{
-    __priv_utils::round_up_to_multiple_usize, decode, decoded_len, encode, encoded_len, Config,
-    DecodeError,
+    __priv_utils::round_up_to_multiple_usize, decode, decoded_len, encode, encoded_len,
+    test_utils::AsBytes, Config, DecodeError, EncodeError,
 };
 
 use rand::rngs::SmallRng;
@@ -19,6 +19,12 @@ fn test_encode_base32() {
             let cfgs = [
                 (base32::BASE32, Config::B32, true),
                 (base32::BASE32_NOPAD, Config::B32.end_padding(false), false),
+                (base32::BASE32HEX, Config::B32_HEX, true),
+                (
+                    base32::BASE32HEX_NOPAD,
+                    Config::B32_HEX.end_padding(false),
+                    false,
+                ),
             ];
 
             const OUT_LEN_PAD: usize = encoded_len($in_length, Config::B32.end_padding(true));
@@ -84,24 +90,56 @@ fn test_encode_b32_errors() {
 
         {
             let err = unpad_cfg.encode::<3>(&[0xAB, 0xCD]).unwrap_err();
-            assert!(err.expected() == 3 && err.found() == 4, "{:?}", err);
+            assert!(
+                matches!(
+                    &err,
+                    EncodeError::WrongOutputLength(x)
+                    if x.expected() == 3 && x.found() == 4
+                ),
+                "{:?}",
+                err
+            );
         }
         assert_eq!(unpad_cfg.encode::<4>(&[0xAB, 0xCD]).unwrap(), "VPGQ");
         {
             let err = unpad_cfg.encode::<5>(&[0xAB, 0xCD]).unwrap_err();
-            assert!(err.expected() == 5 && err.found() == 4, "{:?}", err);
+            assert!(
+                matches!(
+                    &err,
+                    EncodeError::WrongOutputLength(x)
+                    if x.expected() == 5 && x.found() == 4
+                ),
+                "{:?}",
+                err
+            );
         }
     }
 
     // With end padding
     {
         let err = Config::B32.encode::<7>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 7 && err.found() == 8, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 7 && x.found() == 8
+            ),
+            "{:?}",
+            err
+        );
     }
     assert_eq!(Config::B32.encode::<8>(&[0xAB, 0xCD]).unwrap(), "VPGQ====");
     {
         let err = Config::B32.encode::<9>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 9 && err.found() == 8, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 9 && x.found() == 8
+            ),
+            "{:?}",
+            err
+        );
     }
 }
 
@@ -114,6 +152,12 @@ fn test_decode_base32() {
             let cfgs = [
                 (base32::BASE32, Config::B32, true),
                 (base32::BASE32_NOPAD, Config::B32.end_padding(false), false),
+                (base32::BASE32HEX, Config::B32_HEX, true),
+                (
+                    base32::BASE32HEX_NOPAD,
+                    Config::B32_HEX.end_padding(false),
+                    false,
+                ),
             ];
 
             const DECODED_LEN: usize =
@@ -259,6 +303,27 @@ fn test_decode_base32_trailing_bits_err() {
     }
 }
 
+#[test]
+fn test_decode_base32_allow_excess_bits() {
+    let cfg = Config::B32.allow_excess_bits(true);
+
+    // decodes like the same string with the excess bits cleared
+    assert_eq!(decode::<1>(b"67", cfg), decode::<1>(b"64", Config::B32));
+    assert_eq!(decode::<2>(b"6667", cfg), decode::<2>(b"666Q", Config::B32));
+    assert_eq!(
+        decode::<3>(b"66667", cfg),
+        decode::<3>(b"66666", Config::B32)
+    );
+    assert_eq!(
+        decode::<4>(b"6666667=", cfg),
+        decode::<4>(b"666666Y=", Config::B32)
+    );
+    assert_eq!(
+        decode::<1>(b"VV", Config::B32_HEX.allow_excess_bits(true)).unwrap(),
+        [255]
+    );
+}
+
 #[test]
 fn test_decode_base32_errors() {
     {
@@ -279,17 +344,35 @@ fn test_decode_base32_errors() {
         assert_eq!(ok, [0u8; 0]);
     }
 
-    let mut invalid_bytes = crate::test_utils::ByteSet([true; 256]);
-    invalid_bytes.remove_range(b'A'..=b'Z');
-
-    let mut invalid_std_bytes = invalid_bytes.clone();
+    let mut invalid_std_bytes = crate::test_utils::ByteSet([true; 256]);
+    invalid_std_bytes.remove_range(b'A'..=b'Z');
     invalid_std_bytes.remove_range(b'2'..=b'7');
 
-    let invalid_bytes_iters = invalid_std_bytes.iter().map(|b| (Config::B32, b));
+    let mut invalid_hex_bytes = crate::test_utils::ByteSet([true; 256]);
+    invalid_hex_bytes.remove_range(b'0'..=b'9');
+    invalid_hex_bytes.remove_range(b'A'..=b'V');
+
+    let mut invalid_z_bytes = crate::test_utils::ByteSet([true; 256]);
+    for &b in b"ybndrfg8ejkmcpqxot1uwisza345h769" {
+        invalid_z_bytes.remove(b);
+    }
+
+    let invalid_bytes_iters = invalid_std_bytes
+        .iter()
+        .map(|b| (Config::B32, *b"AA\x00A====", b))
+        .chain(
+            invalid_hex_bytes
+                .iter()
+                .map(|b| (Config::B32_HEX, *b"00\x000====", b)),
+        )
+        .chain(
+            invalid_z_bytes
+                .iter()
+                .map(|b| (Config::Z_BASE32.end_padding(true), *b"yy\x00y====", b)),
+        );
 
     // InvalidByte
-    for (cfg, (b, is_invalid)) in invalid_bytes_iters {
-        let mut bytes = *b"AA\x00A====";
+    for (cfg, mut bytes, (b, is_invalid)) in invalid_bytes_iters {
         bytes[2] = b;
         let res = decode::<2>(&bytes, cfg);
 
@@ -375,3 +458,373 @@ fn test_decode_base32_errors() {
         );
     }
 }
+
+#[test]
+fn test_decode_base32_invalid_byte_index() {
+    for index in 0..24 {
+        let mut bytes = [b'A'; 24];
+        bytes[index] = b'!';
+
+        let err = decode::<15>(&bytes, Config::B32).unwrap_err();
+        assert!(
+            matches!(&err, DecodeError::InvalidByte(x) if x.index() == index),
+            "{} {:?}",
+            index,
+            err
+        );
+    }
+}
+
+#[test]
+fn test_lowercase_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    macro_rules! test_case {
+        ($in_length:literal) => {{
+            const OUT_LEN: usize = encoded_len($in_length, Config::B32_LOWER);
+            assert_eq!(OUT_LEN, encoded_len($in_length, Config::B32));
+
+            for _ in 0..GEN_ITERS {
+                let input = rng.gen::<[u8; $in_length]>();
+
+                let standard = encode::<OUT_LEN>(&input, Config::B32).unwrap();
+                let encoded = encode::<OUT_LEN>(&input, Config::B32_LOWER).unwrap();
+                assert_eq!(encoded.as_str(), standard.as_str().to_ascii_lowercase());
+
+                let decoded = decode::<$in_length>(encoded.as_bytes(), Config::B32_LOWER);
+                assert_eq!(decoded.unwrap(), input);
+
+                // uppercase isn't accepted by default
+                if standard.as_str().bytes().any(|b| b.is_ascii_uppercase()) {
+                    assert!(matches!(
+                        decode::<$in_length>(standard.as_bytes(), Config::B32_LOWER),
+                        Err(DecodeError::InvalidByte(_))
+                    ));
+                }
+            }
+        }};
+    }
+
+    test_case!(0);
+    test_case!(1);
+    test_case!(2);
+    test_case!(3);
+    test_case!(4);
+    test_case!(5);
+    test_case!(6);
+    test_case!(11);
+}
+
+#[test]
+fn test_case_insensitive_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for cfg in [Config::B32, Config::B32_LOWER, Config::B32_HEX] {
+        let cfg = cfg.case_insensitive(true);
+
+        for in_length in 0..=20 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 20];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let enc_len = encoded_len(in_length, cfg);
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                let mut mixed = [0u8; 80];
+                let mixed = &mut mixed[..enc_len];
+                for (m, &b) in mixed.iter_mut().zip(encoded) {
+                    *m = if rng.gen() {
+                        b.to_ascii_uppercase()
+                    } else {
+                        b.to_ascii_lowercase()
+                    };
+                }
+
+                assert_eq!(decoded_len(mixed, cfg), in_length);
+
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(mixed, cfg)).unwrap();
+                assert_eq!(decoded, input, "\nmixed: {:?}", mixed);
+            }
+        }
+    }
+
+    // letters outside of the character set are still invalid, in either case
+    let err = decode::<5>(b"0w234567", Config::B32_HEX.case_insensitive(true)).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 1),
+        "{:?}",
+        err
+    );
+    let err = decode::<5>(b"abcdefgW", Config::B32_HEX.case_insensitive(true)).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 7),
+        "{:?}",
+        err
+    );
+
+    // letters that are in the custom character set in both cases keep their value
+    let cfg = Config::custom_b32(b"ABCDEFGHIJKLMNOPabcdefghijklmnop").case_insensitive(true);
+    assert_eq!(decode::<1>(b"Aa", cfg).unwrap(), [0b00000100]);
+    assert_eq!(decode::<1>(b"aA", cfg).unwrap(), [0b10000000]);
+    assert_eq!(decode::<1>(b"Am", cfg).unwrap(), [0b00000111]);
+
+    // other encodings ignore this setting
+    assert!(decode::<2>(
+        b"CAFE",
+        Config::HEX_LOWER.strict_case(true).case_insensitive(true)
+    )
+    .is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_case_insensitive_ignored_letter_panics() {
+    let _ = Config::B32.case_insensitive(true).ignore_bytes(b"a");
+}
+
+const CROCKFORD_CFG: Config = Config::B32_CROCKFORD;
+const CROCKFORD_CHECK_CFG: Config = Config::B32_CROCKFORD.check_symbol(true);
+
+// translates from the standard base 32 character set to `char_set`
+fn translate_standard<const N: usize>(mut encoded: [u8; N], char_set: &[u8; 32]) -> [u8; N] {
+    const STANDARD: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    for b in &mut encoded {
+        if let Some(pos) = STANDARD.iter().position(|x| x == b) {
+            *b = char_set[pos];
+        }
+    }
+    encoded
+}
+
+#[test]
+fn test_crockford_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    macro_rules! test_case {
+        ($in_length:literal) => {{
+            const OUT_LEN: usize = encoded_len($in_length, CROCKFORD_CFG);
+
+            // Crockford never pads
+            assert_eq!(
+                OUT_LEN,
+                encoded_len($in_length, CROCKFORD_CFG.end_padding(true))
+            );
+            assert_eq!(
+                OUT_LEN,
+                encoded_len($in_length, Config::B32.end_padding(false))
+            );
+
+            for _ in 0..GEN_ITERS {
+                let input = rng.gen::<[u8; $in_length]>();
+
+                let standard = encode::<OUT_LEN>(&input, Config::B32.end_padding(false)).unwrap();
+                let encoded = encode::<OUT_LEN>(&input, CROCKFORD_CFG).unwrap();
+                assert_eq!(
+                    encoded.into_array(),
+                    translate_standard(standard.into_array(), b"0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+                );
+
+                let mut lower = encoded.into_array();
+                lower.make_ascii_lowercase();
+                for enc in [encoded.as_slice(), &lower] {
+                    assert_eq!(decoded_len(enc, CROCKFORD_CFG), $in_length);
+                    assert_eq!(decode::<$in_length>(enc, CROCKFORD_CFG).unwrap(), input);
+                }
+            }
+        }};
+    }
+
+    test_case! {0}
+    test_case! {1}
+    test_case! {2}
+    test_case! {3}
+    test_case! {4}
+    test_case! {5}
+    test_case! {6}
+    test_case! {7}
+    test_case! {8}
+}
+
+#[test]
+fn test_crockford_aliases_and_hyphens() {
+    assert_eq!(decode::<2>(b"0M0G", CROCKFORD_CFG).unwrap(), [5, 1]);
+    assert_eq!(decode::<2>(b"oMOg", CROCKFORD_CFG).unwrap(), [5, 1]);
+    assert_eq!(decode::<1>(b"1C", CROCKFORD_CFG).unwrap(), [11]);
+    assert_eq!(decode::<1>(b"IC", CROCKFORD_CFG).unwrap(), [11]);
+    assert_eq!(decode::<1>(b"ic", CROCKFORD_CFG).unwrap(), [11]);
+    assert_eq!(decode::<1>(b"LC", CROCKFORD_CFG).unwrap(), [11]);
+    assert_eq!(decode::<1>(b"lc", CROCKFORD_CFG).unwrap(), [11]);
+
+    assert_eq!(decoded_len(b"-0M-0G--", CROCKFORD_CFG), 2);
+    assert_eq!(decode::<2>(b"-0M-0G--", CROCKFORD_CFG).unwrap(), [5, 1]);
+
+    // `U` is only valid as a check symbol
+    for (input, index) in [(&b"000U"[..], 3), (b"-00-0U", 5), (b"0M-0U", 4)] {
+        let err = decode::<2>(input, CROCKFORD_CFG).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index && x.byte() == b'U'
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    // hyphens don't count towards the length
+    {
+        let err = decode::<2>(b"0-M-0", CROCKFORD_CFG).unwrap_err();
+        assert!(
+            matches!(&err, DecodeError::WrongInputLength(x) if x.length() == 3),
+            "{:?}",
+            err
+        );
+    }
+}
+
+#[test]
+fn test_crockford_check_symbol() {
+    for (input, encoded) in [
+        (&b""[..], "0"),
+        (&[0], "000"),
+        (&[8], "10*"),
+        (&[9], "14U"),
+        (&[18], "28="),
+        (&[27], "3C$"),
+        (&[36], "4G~"),
+        (&[0xFF; 5], "ZZZZZZZZF"),
+        (b"Hello, world!", "91JPRV3F5GG7EVVJDHJ22J"),
+    ] {
+        let out_len = encoded_len(input.len(), CROCKFORD_CHECK_CFG);
+        assert_eq!(out_len, encoded.len());
+        assert_eq!(
+            decoded_len(encoded.as_bytes(), CROCKFORD_CHECK_CFG),
+            input.len()
+        );
+
+        let mut buffer = [0u8; 32];
+        let buffer = &mut buffer[..input.len()];
+
+        macro_rules! with_len {
+            ($($len:literal)*) => {
+                match out_len {
+                    $($len => {
+                        let enc = encode::<$len>(input, CROCKFORD_CHECK_CFG).unwrap();
+                        assert_eq!(enc, encoded);
+
+                        let mut lower = enc.into_array();
+                        lower.make_ascii_lowercase();
+                        let dec = decode::<{$len * 5 / 8}>(&lower, CROCKFORD_CHECK_CFG).unwrap();
+                        buffer.copy_from_slice(&dec);
+                    })*
+                    _ => unreachable!(),
+                }
+            };
+        }
+        with_len! {1 3 9 22}
+
+        assert_eq!(buffer, input);
+    }
+
+    // hyphens before the check symbol
+    assert_eq!(decode::<1>(b"28-=", CROCKFORD_CHECK_CFG).unwrap(), [18]);
+    assert_eq!(decode::<1>(b"2-8-=-", CROCKFORD_CHECK_CFG).unwrap(), [18]);
+
+    {
+        let err = decode::<1>(b"28-*", CROCKFORD_CHECK_CFG).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongCheckSymbol(x)
+                if x.index() == 3 && x.expected() == b'=' && x.found() == b'*'
+            ),
+            "{:?}",
+            err
+        );
+    }
+    {
+        let err = decode::<1>(b"28!", CROCKFORD_CHECK_CFG).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == 2 && x.byte() == b'!'
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for input in [&b""[..], b"-", b"--"] {
+        let err = decode::<0>(input, CROCKFORD_CHECK_CFG).unwrap_err();
+        assert!(
+            matches!(&err, DecodeError::WrongInputLength(x) if x.length() == 0),
+            "{:?}",
+            err
+        );
+    }
+}
+
+#[test]
+fn test_check_symbol_non_crockford() {
+    // other encodings ignore the check symbol setting
+    for have in [false, true] {
+        let cfg = Config::B32.check_symbol(have);
+        assert_eq!(encoded_len(3, cfg), 8);
+        assert_eq!(encode::<8>(b"foo", cfg).unwrap(), "MZXW6===");
+        assert_eq!(decode::<3>(b"MZXW6===", cfg).unwrap(), *b"foo");
+
+        let cfg = Config::B32_LOWER.check_symbol(have);
+        assert_eq!(encode::<8>(b"foo", cfg).unwrap(), "mzxw6===");
+    }
+}
+
+#[test]
+fn test_zbase32_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    macro_rules! test_case {
+        ($in_length:literal) => {{
+            const OUT_LEN: usize = encoded_len($in_length, Config::Z_BASE32);
+            const OUT_LEN_PAD: usize = encoded_len($in_length, Config::Z_BASE32.end_padding(true));
+
+            for _ in 0..GEN_ITERS {
+                let input = rng.gen::<[u8; $in_length]>();
+
+                let standard = encode::<OUT_LEN_PAD>(&input, Config::B32).unwrap();
+                let expected =
+                    translate_standard(standard.into_array(), b"ybndrfg8ejkmcpqxot1uwisza345h769");
+
+                let padded =
+                    encode::<OUT_LEN_PAD>(&input, Config::Z_BASE32.end_padding(true)).unwrap();
+                assert_eq!(padded.into_array(), expected);
+
+                let unpadded = encode::<OUT_LEN>(&input, Config::Z_BASE32).unwrap();
+                assert_eq!(unpadded.as_slice(), &expected[..OUT_LEN]);
+
+                assert_eq!(
+                    decode::<$in_length>(unpadded.as_slice(), Config::Z_BASE32).unwrap(),
+                    input
+                );
+                assert_eq!(
+                    decode::<$in_length>(&expected, Config::Z_BASE32.end_padding(true)).unwrap(),
+                    input
+                );
+            }
+        }};
+    }
+
+    test_case! {0}
+    test_case! {1}
+    test_case! {2}
+    test_case! {3}
+    test_case! {4}
+    test_case! {5}
+    test_case! {6}
+    test_case! {7}
+    test_case! {8}
+}
@@ -0,0 +1,172 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, Config, DecodeError, EncodeError,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+// test vectors from RFC 9285
+const VECTORS: &[(&[u8], &[u8])] = &[
+    (b"AB", b"BB8"),
+    (b"Hello!!", b"%69 VD92EX0"),
+    (b"base-45", b"UJCLQE7W581"),
+    (b"ietf!", b"QED8WEX0"),
+];
+
+#[test]
+fn test_base45_vectors() {
+    for &(unencoded, encoded) in VECTORS {
+        assert_eq!(encoded_len(unencoded.len(), Config::B45), encoded.len());
+
+        let mut buffer = [0u8; 16];
+        let res = call_with_len!(buffer, encoded.len(), encode(unencoded, Config::B45)).unwrap();
+        assert_eq!(res, encoded);
+
+        assert_eq!(decoded_len(encoded, Config::B45), unencoded.len());
+
+        let mut buffer = [0u8; 16];
+        let res = call_with_len!(buffer, unencoded.len(), decode(encoded, Config::B45)).unwrap();
+        assert_eq!(res, unencoded);
+    }
+}
+
+#[test]
+fn test_encode_decode_base45() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=40 {
+        for _ in 0..GEN_ITERS {
+            let mut input = [0u8; 40];
+            let input = &mut input[..in_length];
+            rng.fill(&mut *input);
+
+            let expected = base45::encode(&*input);
+            let expected = expected.as_bytes();
+
+            assert_eq!(encoded_len(in_length, Config::B45), expected.len());
+
+            let mut buffer = [0u8; 80];
+            let encoded =
+                call_with_len!(buffer, expected.len(), encode(input, Config::B45)).unwrap();
+            assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+            assert_eq!(decoded_len(encoded, Config::B45), in_length);
+
+            let mut buffer = [0u8; 80];
+            let decoded = call_with_len!(buffer, in_length, decode(encoded, Config::B45)).unwrap();
+            assert_eq!(decoded, input);
+        }
+    }
+}
+
+#[test]
+fn test_base45_macros() {
+    const DECODED: &[u8] = crate::decode!("%69 VD92EX0", Config::B45);
+    assert_eq!(DECODED, b"Hello!!");
+
+    const ENCODED: &str = crate::encode_as_str!(b"ietf!", Config::B45);
+    assert_eq!(ENCODED, "QED8WEX0");
+}
+
+#[test]
+fn test_base45_errors() {
+    // InvalidByte
+    {
+        let mut invalid_bytes = crate::test_utils::ByteSet([true; 256]);
+        for &b in b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:" {
+            invalid_bytes.remove(b);
+        }
+
+        for (b, is_invalid) in invalid_bytes.iter() {
+            let mut bytes = *b"000000";
+            bytes[4] = b;
+            let res = decode::<4>(&bytes, Config::B45);
+
+            if is_invalid {
+                let err = res.unwrap_err();
+                assert!(
+                    matches!(
+                        &err,
+                        DecodeError::InvalidByte(x)
+                        if x.index() == 4 &&
+                            x.byte() == b &&
+                            x.encoding() == crate::Encoding::Base45
+                    ),
+                    "{:?}",
+                    err
+                );
+            } else {
+                res.unwrap();
+            }
+        }
+    }
+
+    // WrongInputLength
+    for input in [&b"0"[..], b"0000", b"%69 VD92EX0AB"] {
+        let err = decode::<0>(input, Config::B45).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongInputLength(x) if x.length() == input.len()
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // ChunkOverflow
+    for (input, index) in [
+        (&b"GGW"[..], 0),
+        (b":::", 0),
+        (b"000GGW000", 3),
+        (b"V5", 0),
+        (b"000::", 3),
+    ] {
+        let mut buffer = [0u8; 8];
+        let len = decoded_len(input, Config::B45);
+        let err = call_with_len!(buffer, len, decode(input, Config::B45)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::ChunkOverflow(x)
+                if x.index() == index && x.encoding() == crate::Encoding::Base45
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+    assert_eq!(decode::<2>(b"FGW", Config::B45), Ok([0xFF; 2]));
+    assert_eq!(decode::<1>(b"U5", Config::B45), Ok([0xFF]));
+
+    // WrongOutputLength
+    for out_len in [0, 6, 8] {
+        let mut buffer = [0u8; 8];
+        let err = call_with_len!(buffer, out_len, decode(b"%69 VD92EX0", Config::B45)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongOutputLength(x)
+                if x.expected() == 7 && x.found() == out_len
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for out_len in [0, 10, 12] {
+        let mut buffer = [0u8; 12];
+        let err = call_with_len!(buffer, out_len, encode(b"Hello!!", Config::B45)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == out_len && x.found() == 11
+            ),
+            "{:?}",
+            err
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{
+    decode, decode_to_slice, decoded_len, encode, encode_to_slice, encoded_len, encoded_len_of,
+    test_utils::AsBytes, Config, DecodeError, EncodeError, Encoding,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+const CFGS: [(Config, &bs58::Alphabet); 3] = [
+    (Config::B58, bs58::Alphabet::BITCOIN),
+    (Config::B58_FLICKR, bs58::Alphabet::FLICKR),
+    (Config::B58_RIPPLE, bs58::Alphabet::RIPPLE),
+];
+
+#[test]
+fn test_encode_decode_base58() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=20 {
+        for (cfg, alphabet) in CFGS {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 20];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                // leading zeros are encoded specially
+                let zeros = rng.gen_range(0..=in_length.min(3));
+                input[..zeros].fill(0);
+
+                let mut expected = [0u8; 32];
+                let expected_len = bs58::encode(&*input)
+                    .with_alphabet(alphabet)
+                    .into(&mut expected[..])
+                    .unwrap();
+                let expected = &expected[..expected_len];
+
+                assert_eq!(encoded_len_of(input, cfg), expected_len);
+                assert!(encoded_len(in_length, cfg) >= expected_len);
+
+                let mut buffer = [0u8; 32];
+                let encoded = call_with_len!(buffer, expected_len, encode(input, cfg)).unwrap();
+                assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+                assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                let mut buffer = [0u8; 32];
+                let decoded = call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                assert_eq!(decoded, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_base58_max_len() {
+    for in_length in 0..=64 {
+        let input = [0xFFu8; 64];
+        let input = &input[..in_length];
+
+        let mut expected = [0u8; 128];
+        let expected_len = bs58::encode(input).into(&mut expected[..]).unwrap();
+
+        assert_eq!(encoded_len(in_length, Config::B58), expected_len);
+        assert_eq!(encoded_len_of(input, Config::B58), expected_len);
+    }
+
+    {
+        let input = [0xFFu8; 1024];
+        assert_eq!(encoded_len_of(&input, Config::B58), 1399);
+
+        let encoded = [b'z'; 1024];
+        assert_eq!(decoded_len(&encoded, Config::B58), 750);
+    }
+}
+
+#[test]
+fn test_base58_long_input_len() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in [1500, 1600, 3000, 4096] {
+        let mut input = [0u8; 4096];
+        let input = &mut input[..in_length];
+        rng.fill(&mut *input);
+        input[..10].fill(0);
+
+        let mut expected = [0u8; 6000];
+        let expected_len = bs58::encode(&*input).into(&mut expected[..]).unwrap();
+        let expected = &expected[..expected_len];
+
+        assert_eq!(encoded_len_of(input, Config::B58), expected_len);
+        assert_eq!(decoded_len(expected, Config::B58), in_length);
+
+        // the error paths report the exact lengths
+        let mut out = [0u8; 16];
+
+        match encode_to_slice(input, &mut out, Config::B58) {
+            Err(EncodeError::WrongOutputLength(err)) => {
+                assert_eq!(err.expected(), expected_len);
+                assert_eq!(err.found(), 16);
+            }
+            x => panic!("{:?}", x),
+        }
+
+        match decode_to_slice(expected, &mut out, Config::B58) {
+            Err(DecodeError::WrongOutputLength(err)) => {
+                assert_eq!(err.expected(), in_length);
+                assert_eq!(err.found(), 16);
+            }
+            x => panic!("{:?}", x),
+        }
+    }
+
+    {
+        let input = [0xFFu8; 1600];
+        assert_eq!(encoded_len_of(&input, Config::B58), 2186);
+        assert_eq!(encoded_len(1600, Config::B58), 2186);
+
+        let input = [0x01u8; 1600];
+        assert_eq!(encoded_len_of(&input, Config::B58), 2184);
+
+        let encoded = [b'z'; 4096];
+        assert_eq!(decoded_len(&encoded, Config::B58), 3000);
+    }
+}
+
+#[test]
+#[cfg(not(miri))]
+fn test_base58_len_stack_usage() {
+    // computing lengths at runtime uses at most a few KiB of stack,
+    // inputs past that limit get an upper bound of their length
+    let thread = std::thread::Builder::new().stack_size(64 * 1024).spawn(|| {
+        let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+        let mut input = std::vec![0u8; 8000];
+        rng.fill(&mut input[..]);
+
+        let mut buffer = std::vec![0u8; 12000];
+
+        let expected_len = bs58::encode(&input[..5000]).into(&mut buffer[..]).unwrap();
+        let expected = &buffer[..expected_len];
+        assert_eq!(encoded_len_of(&input[..5000], Config::B58), expected_len);
+        assert_eq!(decoded_len(expected, Config::B58), 5000);
+
+        let expected_len = bs58::encode(&input).into(&mut buffer[..]).unwrap();
+        let expected = &buffer[..expected_len];
+        let len = encoded_len_of(&input, Config::B58);
+        assert_eq!(len, encoded_len(8000, Config::B58));
+        assert!(len >= expected_len);
+
+        let len = decoded_len(expected, Config::B58);
+        assert!(len >= 8000, "{}", len);
+    });
+    thread.unwrap().join().unwrap();
+}
+
+#[test]
+fn test_base58_macros() {
+    const ENCODED: &str = crate::encode_as_str!(b"\0\0hello world", Config::B58);
+    assert_eq!(ENCODED, "11StV1DL6CwTryKyV");
+
+    const DECODED: &[u8] = crate::decode!(ENCODED, Config::B58);
+    assert_eq!(DECODED, b"\0\0hello world");
+
+    const EMPTY: &str = crate::encode_as_str!(b"", Config::B58);
+    assert_eq!(EMPTY, "");
+
+    // longer than the smallest buffer used to compute lengths
+    const LONG: &[u8; 1600] = &[0x01; 1600];
+    const LONG_ENCODED: &str = crate::encode_as_str!(LONG, Config::B58);
+    assert_eq!(LONG_ENCODED.len(), 2184);
+
+    const LONG_DECODED: &[u8] = crate::decode!(LONG_ENCODED, Config::B58);
+    assert_eq!(LONG_DECODED, LONG);
+}
+
+#[test]
+fn test_base58_errors() {
+    // WrongOutputLength
+    {
+        let err = encode::<3>(&[0, 0, 1], Config::B58).unwrap();
+        assert_eq!(err, "112");
+    }
+    for out_len in [2, 4] {
+        let mut buffer = [0u8; 4];
+        let err = call_with_len!(buffer, out_len, encode(&[0, 0, 1], Config::B58)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == out_len && x.found() == 3
+            ),
+            "{:?}",
+            err
+        );
+    }
+    {
+        let err = encode::<1>(&[0xFF; 4], Config::B58).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 1 && x.found() == 6
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for out_len in [0, 2, 4, 5] {
+        let mut buffer = [0u8; 5];
+        let err = call_with_len!(buffer, out_len, decode(b"11z", Config::B58)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongOutputLength(x)
+                if x.expected() == 3 && x.found() == out_len
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    // InvalidByte
+    for cfg in CFGS.map(|x| x.0) {
+        let mut invalid_bytes = crate::test_utils::ByteSet([true; 256]);
+        let char_set = match cfg.encoding {
+            Encoding::Base58(x) => x,
+            _ => unreachable!(),
+        };
+        for &b in &char_set.lookup().into_enc {
+            invalid_bytes.remove(b);
+        }
+
+        for (b, is_invalid) in invalid_bytes.iter() {
+            let mut bytes = *b"ppp\0p";
+            bytes[3] = b;
+            let res = decode::<4>(&bytes, cfg);
+
+            if is_invalid {
+                let err = res.unwrap_err();
+                assert!(
+                    matches!(
+                        &err,
+                        DecodeError::InvalidByte(x)
+                        if x.index() == 3 &&
+                            x.byte() == b &&
+                            x.encoding() == cfg.encoding
+                    ),
+                    "{:?}",
+                    err
+                );
+            } else {
+                decoded_len(&bytes, cfg);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encode_decode_base58check() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=16 {
+        for (cfg, alphabet) in CFGS {
+            let cfg = cfg.checksum(true);
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 16];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let zeros = rng.gen_range(0..=in_length.min(3));
+                input[..zeros].fill(0);
+
+                let mut expected = [0u8; 32];
+                let expected_len = bs58::encode(&*input)
+                    .with_alphabet(alphabet)
+                    .with_check()
+                    .into(&mut expected[..])
+                    .unwrap();
+                let expected = &expected[..expected_len];
+
+                assert_eq!(encoded_len_of(input, cfg), expected_len);
+                assert!(encoded_len(in_length, cfg) >= expected_len);
+
+                let mut buffer = [0u8; 32];
+                let encoded = call_with_len!(buffer, expected_len, encode(input, cfg)).unwrap();
+                assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+                assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                let mut buffer = [0u8; 32];
+                let decoded = call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                assert_eq!(decoded, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_base58check_errors() {
+    const ADDR: &[u8] = b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+
+    // ChecksumMismatch
+    {
+        let mut bytes = [0u8; 34];
+        bytes.copy_from_slice(ADDR);
+        bytes[10] = b'x';
+
+        let mut expected = [0u8; 25];
+        bs58::decode(&bytes[..]).into(&mut expected[..]).unwrap();
+
+        let err = decode::<21>(&bytes, Config::B58_CHECK).unwrap_err();
+        match err {
+            DecodeError::ChecksumMismatch(x) => {
+                let hash = <sha2::Sha256 as sha2::Digest>::digest(&expected[..21]);
+                let hash = <sha2::Sha256 as sha2::Digest>::digest(&hash);
+                assert_eq!(x.expected(), hash[..4]);
+                assert_eq!(x.found(), expected[21..]);
+            }
+            _ => panic!("{:?}", err),
+        }
+    }
+
+    // WrongInputLength, when there's less than 4 decoded bytes
+    for input in [&b""[..], b"1", b"111", b"zzz"] {
+        let err = decode::<0>(input, Config::B58_CHECK).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongInputLength(x) if x.length() == input.len()
+            ),
+            "{:?}",
+            err
+        );
+        assert_eq!(decoded_len(input, Config::B58_CHECK), 0);
+    }
+
+    // WrongOutputLength
+    for out_len in [0, 20, 22, 25] {
+        let mut buffer = [0u8; 25];
+        let err = call_with_len!(buffer, out_len, decode(ADDR, Config::B58_CHECK)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongOutputLength(x)
+                if x.expected() == 21 && x.found() == out_len
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for out_len in [0, 26, 28, 30] {
+        let mut buffer = [0u8; 30];
+        let err = call_with_len!(buffer, out_len, encode(&[0; 21], Config::B58_CHECK));
+        let err = err.unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == out_len && x.found() == 27
+            ),
+            "{:?}",
+            err
+        );
+    }
+}
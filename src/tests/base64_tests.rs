@@ -1,5 +1,5 @@
 use crate::{decode, decoded_len, encode, encoded_len};
-use crate::{B64CharSet, Config, DecodeError, Encoding};
+use crate::{B64CharSet, Config, DecodeError, EncodeError, Encoding};
 
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -166,24 +166,56 @@ fn test_encode_b64_errors() {
 
         {
             let err = unpad_cfg.encode::<2>(&[0xAB, 0xCD]).unwrap_err();
-            assert!(err.expected() == 2 && err.found() == 3, "{:?}", err);
+            assert!(
+                matches!(
+                    &err,
+                    EncodeError::WrongOutputLength(x)
+                    if x.expected() == 2 && x.found() == 3
+                ),
+                "{:?}",
+                err
+            );
         }
         assert_eq!(unpad_cfg.encode::<3>(&[0xAB, 0xCD]).unwrap(), "q80");
         {
             let err = unpad_cfg.encode::<4>(&[0xAB, 0xCD]).unwrap_err();
-            assert!(err.expected() == 4 && err.found() == 3, "{:?}", err);
+            assert!(
+                matches!(
+                    &err,
+                    EncodeError::WrongOutputLength(x)
+                    if x.expected() == 4 && x.found() == 3
+                ),
+                "{:?}",
+                err
+            );
         }
     }
 
     // With end padding
     {
         let err = Config::B64.encode::<3>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 3 && err.found() == 4, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 3 && x.found() == 4
+            ),
+            "{:?}",
+            err
+        );
     }
     assert_eq!(Config::B64.encode::<4>(&[0xAB, 0xCD]).unwrap(), "q80=");
     {
         let err = Config::B64.encode::<5>(&[0xAB, 0xCD]).unwrap_err();
-        assert!(err.expected() == 5 && err.found() == 4, "{:?}", err);
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == 5 && x.found() == 4
+            ),
+            "{:?}",
+            err
+        );
     }
 }
 
@@ -260,6 +292,35 @@ fn test_decode_base64_trailing_bits_err() {
     }
 }
 
+#[test]
+fn test_decode_base64_allow_excess_bits() {
+    let cfg = Config::B64.allow_excess_bits(true);
+
+    // decodes like the same string with the excess bits cleared
+    assert_eq!(decode::<1>(b"+/", cfg), decode::<1>(b"+w", Config::B64));
+    assert_eq!(decode::<1>(b"+/==", cfg), decode::<1>(b"+w==", Config::B64));
+    assert_eq!(decode::<2>(b"++/", cfg), decode::<2>(b"++8", Config::B64));
+    assert_eq!(decode::<2>(b"++B", cfg), decode::<2>(b"++A", Config::B64));
+    assert_eq!(
+        decode::<4>(b"+++++/", cfg),
+        decode::<4>(b"+++++w", Config::B64)
+    );
+    assert_eq!(
+        decode::<1>(b"_-", Config::B64_URL_SAFE.allow_excess_bits(true)).unwrap(),
+        [255]
+    );
+
+    // the other errors are still reported
+    assert!(matches!(
+        decode::<1>(b"+", cfg),
+        Err(DecodeError::WrongInputLength(_))
+    ));
+    assert!(matches!(
+        decode::<2>(b"+!/", cfg),
+        Err(DecodeError::InvalidByte(_))
+    ));
+}
+
 #[test]
 fn test_decode_base64_errors() {
     {
@@ -293,11 +354,11 @@ fn test_decode_base64_errors() {
     invalid_bytes.remove_range(b'a'..=b'z');
     invalid_bytes.remove_range(b'0'..=b'9');
 
-    let mut invalid_std_bytes = invalid_bytes.clone();
+    let mut invalid_std_bytes = invalid_bytes;
     invalid_std_bytes.remove(b'+');
     invalid_std_bytes.remove(b'/');
 
-    let mut invalid_url_bytes = invalid_bytes.clone();
+    let mut invalid_url_bytes = invalid_bytes;
     invalid_url_bytes.remove(b'-');
     invalid_url_bytes.remove(b'_');
 
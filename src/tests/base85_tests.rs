@@ -0,0 +1,288 @@
+use crate::{
+    decode, decoded_len, encode, encode_to_slice, encoded_len, encoded_len_of, test_utils::AsBytes,
+    B85CharSet, Config, DecodeError, EncodeError, Encoding,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+const CFGS: [Config; 4] = [
+    Config::ASCII85,
+    Config::ASCII85.delimiters(true),
+    Config::Z85,
+    Config::B85,
+];
+
+// (unencoded, Ascii85, RFC 1924), generated with Python's `base64` module
+const VECTORS: &[(&[u8], &[u8], &[u8])] = &[
+    (b"", b"", b""),
+    (b"h", b"BE", b"Xa"),
+    (b"he", b"BOq", b"Xk`"),
+    (b"hel", b"BOtu", b"Xk}~"),
+    (b"hell", b"BOu!r", b"Xk~0{"),
+    (b"hello", b"BOu!rDZ", b"Xk~0{Zv"),
+    (b"\x00", b"!!", b"00"),
+    (b"\x00\x00\x00\x00", b"z", b"00000"),
+    (b"\x00\x00\x00\x00\x00", b"z!!", b"0000000"),
+    (
+        b"\x00\x00\x00\x00\xFF\xFF\xFF\xFF\x00\x00\x00",
+        b"zs8W-!!!!!",
+        b"00000|NsC00000",
+    ),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", b"s8W-!s8W*", b"|NsC0|Ns9"),
+    (
+        b"Man is distinguished",
+        b"9jqo^BlbD-BleB1DJ+*+F(f,q",
+        b"O<`^zX>%ZCX>)XGZfA9Ab7*B`",
+    ),
+];
+
+#[test]
+fn test_base85_vectors() {
+    for &(unencoded, ascii85, rfc1924) in VECTORS {
+        for (cfg, encoded) in [(Config::ASCII85, ascii85), (Config::B85, rfc1924)] {
+            assert_eq!(encoded_len_of(unencoded, cfg), encoded.len());
+
+            let mut buffer = [0u8; 80];
+            let res = call_with_len!(buffer, encoded.len(), encode(unencoded, cfg)).unwrap();
+            assert_eq!(res, encoded);
+
+            assert_eq!(decoded_len(encoded, cfg), unencoded.len());
+
+            let mut buffer = [0u8; 80];
+            let res = call_with_len!(buffer, unencoded.len(), decode(encoded, cfg)).unwrap();
+            assert_eq!(res, unencoded);
+        }
+    }
+}
+
+#[test]
+fn test_encode_decode_z85() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in (0..=40).step_by(4) {
+        for _ in 0..GEN_ITERS {
+            let mut input = [0u8; 40];
+            let input = &mut input[..in_length];
+            rng.fill(&mut *input);
+
+            let expected = z85::encode(&*input);
+            let expected = expected.as_bytes();
+
+            let mut buffer = [0u8; 80];
+            let encoded =
+                call_with_len!(buffer, expected.len(), encode(input, Config::Z85)).unwrap();
+            assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+            let mut buffer = [0u8; 80];
+            let decoded = call_with_len!(buffer, in_length, decode(encoded, Config::Z85)).unwrap();
+            assert_eq!(decoded, input);
+        }
+    }
+}
+
+#[test]
+fn test_encode_decode_base85() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=40 {
+        for cfg in CFGS {
+            if cfg.encoding == Encoding::Base85(B85CharSet::Z85) && in_length % 4 != 0 {
+                continue;
+            }
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 40];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                // makes chunks of zeros more likely, to test `z` compression
+                for chunk in input.chunks_mut(4) {
+                    if rng.gen_bool(0.25) {
+                        chunk.fill(0);
+                    }
+                }
+
+                let enc_len = encoded_len_of(input, cfg);
+                assert!(encoded_len(in_length, cfg) >= enc_len);
+
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                assert_eq!(decoded, input, "\nencoded: {:?}", encoded);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_base85_macros() {
+    const ENCODED: &str = crate::encode_as_str!(b"\0\0\0\0hello", Config::ASCII85);
+    assert_eq!(ENCODED, "zBOu!rDZ");
+
+    const DECODED: &[u8] = crate::decode!(ENCODED, Config::ASCII85);
+    assert_eq!(DECODED, b"\0\0\0\0hello");
+
+    const DELIMITED: &str = crate::encode_as_str!(b"\0\0\0\0", Config::ASCII85.delimiters(true));
+    assert_eq!(DELIMITED, "<~z~>");
+}
+
+#[test]
+fn test_base85_delimiters() {
+    // delimiters are accepted regardless of the setting
+    for cfg in [Config::ASCII85, Config::ASCII85.delimiters(true)] {
+        assert_eq!(decode::<5>(b"<~BOu!rDZ~>", cfg).unwrap(), *b"hello");
+        assert_eq!(decode::<5>(b"BOu!rDZ", cfg).unwrap(), *b"hello");
+        assert_eq!(decoded_len(b"<~BOu!rDZ~>", cfg), 5);
+        assert_eq!(decode::<0>(b"<~~>", cfg).unwrap(), [0u8; 0]);
+    }
+
+    // only Ascii85 supports delimiters
+    assert_eq!(
+        encode::<7>(b"hello", Config::B85.delimiters(true)).unwrap(),
+        "Xk~0{Zv"
+    );
+}
+
+#[test]
+fn test_z85_partial_chunk_encode() {
+    // the length is computed as if partial chunks were allowed
+    assert_eq!(encoded_len(5, Config::Z85), 7);
+    assert_eq!(encoded_len_of(b"hello", Config::Z85), 7);
+
+    for len in [1, 2, 3, 5, 6, 7] {
+        let input = &[0xAB; 8][..len];
+        let mut out = [0u8; 16];
+
+        assert!(
+            matches!(
+                encode::<10>(input, Config::Z85),
+                Err(EncodeError::WrongInputLength(x))
+                if x.length() == len && x.encoding() == Config::Z85.encoding
+            ),
+            "{}",
+            len
+        );
+        assert!(
+            matches!(
+                encode_to_slice(input, &mut out, Config::Z85),
+                Err(EncodeError::WrongInputLength(x)) if x.length() == len
+            ),
+            "{}",
+            len
+        );
+    }
+}
+
+#[test]
+fn test_base85_errors() {
+    // InvalidByte
+    for (cfg, input, index) in [
+        (Config::ASCII85, &b"BOu!v"[..], 4),
+        (Config::ASCII85, b"BzOu!", 1),
+        (Config::ASCII85, b"<~BOzu!~>", 4),
+        (Config::ASCII85, b"<~BOu!r", 1),
+        (Config::ASCII85, b"BOu!r~>", 5),
+        (Config::Z85, b"Hello World", 5),
+        (Config::Z85, b"zzzz\"", 4),
+        (Config::B85, b"Xk~0{\"", 5),
+        (Config::B85, b"z,000", 1),
+    ] {
+        let mut buffer = [0u8; 16];
+        let len = decoded_len(input, cfg);
+        let err = call_with_len!(buffer, len, decode(input, cfg)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index &&
+                    x.byte() == input[index] &&
+                    x.encoding() == cfg.encoding
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // WrongInputLength
+    for (cfg, input) in [
+        (Config::ASCII85, &b"B"[..]),
+        (Config::ASCII85, b"BOu!rD"),
+        (Config::ASCII85, b"zB"),
+        (Config::ASCII85, b"<~BOu!rD~>"),
+        (Config::B85, b"Xk~0{Z"),
+        (Config::Z85, b"Hell"),
+        (Config::Z85, b"HelloWorl"),
+    ] {
+        let err = decode::<0>(input, cfg).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongInputLength(x) if x.length() == input.len()
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // ChunkOverflow
+    for (cfg, input, index) in [
+        (Config::ASCII85, &b"s8W-\""[..], 0),
+        (Config::ASCII85, b"zs8W-\"", 1),
+        (Config::ASCII85, b"<~!!!!!uuuuu~>", 7),
+        (Config::ASCII85, b"s8W-!s8X", 5),
+        (Config::B85, b"|NsC1", 0),
+        (Config::Z85, b"#####", 0),
+    ] {
+        let mut buffer = [0u8; 16];
+        let len = decoded_len(input, cfg);
+        let err = call_with_len!(buffer, len, decode(input, cfg)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::ChunkOverflow(x)
+                if x.index() == index && x.encoding() == cfg.encoding
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // WrongOutputLength
+    for out_len in [0, 4, 6] {
+        let mut buffer = [0u8; 6];
+        let err = call_with_len!(buffer, out_len, decode(b"BOu!rDZ", Config::ASCII85)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongOutputLength(x)
+                if x.expected() == 5 && x.found() == out_len
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for out_len in [0, 2, 5] {
+        let mut buffer = [0u8; 5];
+        let err =
+            call_with_len!(buffer, out_len, encode(b"\0\0\0\0h", Config::ASCII85)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == out_len && x.found() == 3
+            ),
+            "{:?}",
+            err
+        );
+    }
+}
@@ -0,0 +1,426 @@
+use crate::{
+    decode, decode_to_slice, decoded_len, encode, encode_to_slice, encoded_len,
+    test_utils::AsBytes, Bech32Variant, Config, DecodeError, EncodeError, Encoding, InvalidHrp,
+};
+
+use bech32::{primitives::decode::CheckedHrpstring, Bech32, Bech32m, Hrp};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+// Configs that decode strings with any human-readable part
+const BECH32: Config = Config::new(Encoding::Bech32(Bech32Variant::Bech32));
+const BECH32M: Config = Config::new(Encoding::Bech32(Bech32Variant::Bech32m));
+
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const HRPS: &[&str] = &["a", "bc", "tb", "hi", "lnbc", "?", "split"];
+
+const BIP173_VALID: &[&str] = &[
+    "A12UEL5L",
+    "a12uel5l",
+    "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+    "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+    "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+    "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+    "?1ezyfcl",
+];
+
+const BIP350_VALID: &[&str] = &[
+    "A1LQFN3A",
+    "a1lqfn3a",
+    "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+    "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+    "11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+    "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+    "?1v759aa",
+];
+
+// A `fmt::Write` that writes into a fixed-size buffer
+struct ArrayWriter {
+    buffer: [u8; 128],
+    len: usize,
+}
+
+impl ArrayWriter {
+    const fn new() -> Self {
+        Self {
+            buffer: [0; 128],
+            len: 0,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+}
+
+impl core::fmt::Write for ArrayWriter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.buffer[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_encode_decode_bech32() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=40 {
+        for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+            for _ in 0..GEN_ITERS {
+                let hrp = HRPS[rng.gen_range(0..HRPS.len())];
+                let cfg = Config::new(Encoding::Bech32(variant)).hrp(hrp);
+
+                let mut input = [0u8; 40];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let mut expected = ArrayWriter::new();
+                let parsed_hrp = Hrp::parse(hrp).unwrap();
+                match variant {
+                    Bech32Variant::Bech32 => {
+                        bech32::encode_lower_to_fmt::<Bech32, _>(&mut expected, parsed_hrp, input)
+                    }
+                    Bech32Variant::Bech32m => {
+                        bech32::encode_lower_to_fmt::<Bech32m, _>(&mut expected, parsed_hrp, input)
+                    }
+                }
+                .unwrap();
+                let expected = expected.as_bytes();
+
+                assert_eq!(encoded_len(in_length, cfg), expected.len());
+
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, expected.len(), encode(input, cfg)).unwrap();
+                assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+                let mut upper = [0u8; 80];
+                let upper = &mut upper[..encoded.len()];
+                upper.copy_from_slice(encoded);
+                upper.make_ascii_uppercase();
+
+                for encoded in [encoded, &*upper] {
+                    for cfg in [cfg, Config::new(Encoding::Bech32(variant))] {
+                        assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                        let mut buffer = [0u8; 80];
+                        let decoded =
+                            call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                        assert_eq!(decoded, input);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bech32_valid_checksums() {
+    for (cfg, strings) in [(BECH32, BIP173_VALID), (BECH32M, BIP350_VALID)] {
+        for string in strings {
+            let mut buffer = [0u8; 80];
+            let len = decoded_len(string.as_bytes(), cfg);
+            let res = call_with_len!(buffer, len, decode(string.as_bytes(), cfg));
+
+            // the data in some of the test vectors isn't a whole amount of bytes
+            match res {
+                Ok(_) | Err(DecodeError::WrongInputLength(_) | DecodeError::ExcessBits(_)) => {}
+                Err(e) => panic!("{:?}\n{}", e, string),
+            }
+
+            let other_cfg = match cfg.encoding {
+                Encoding::Bech32(Bech32Variant::Bech32) => BECH32M,
+                _ => BECH32,
+            };
+            let res = call_with_len!(buffer, len, decode(string.as_bytes(), other_cfg));
+            assert!(
+                matches!(res, Err(DecodeError::ChecksumMismatch(_))),
+                "{:?}\n{}",
+                res,
+                string
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bech32_matches_library_decoding() {
+    for (cfg, string) in [
+        (BECH32, "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"),
+        (BECH32M, "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx"),
+    ] {
+        let expected = match cfg.encoding {
+            Encoding::Bech32(Bech32Variant::Bech32) => CheckedHrpstring::new::<Bech32>(string),
+            _ => CheckedHrpstring::new::<Bech32m>(string),
+        }
+        .unwrap();
+
+        let mut expected_bytes = [0u8; 20];
+        let mut len = 0;
+        for b in expected.byte_iter() {
+            expected_bytes[len] = b;
+            len += 1;
+        }
+
+        assert_eq!(decoded_len(string.as_bytes(), cfg), len);
+        assert_eq!(
+            decode::<20>(string.as_bytes(), cfg).unwrap()[..],
+            expected_bytes[..len]
+        );
+    }
+}
+
+#[test]
+fn test_bech32_errors() {
+    fn decode_err(input: &[u8], cfg: Config) -> DecodeError {
+        let mut buffer = [0u8; 80];
+        let len = decoded_len(input, cfg);
+        call_with_len!(buffer, len, decode(input, cfg)).unwrap_err()
+    }
+
+    // InvalidByte
+    for (cfg, input, index) in [
+        (BECH32, &b"\x201nwldj5"[..], 0),
+        (BECH32, b"\x7F1axkwrx", 0),
+        (BECH32, b"\x801eym55h", 0),
+        (BECH32, b"x1b4n0q5v", 2),
+        (BECH32, b"de1lg7wt\xFF", 8),
+        (BECH32M, b"y1b0jsk6g", 2),
+        (BECH32M, b"lt1igcx5c0", 3),
+        (BECH32M, b"mm1crxm3i", 8),
+        (BECH32M, b"au1s5cgom", 7),
+        // mixed case
+        (BECH32, b"A12uEL5L", 3),
+        (BECH32, b"a12UEL5L", 3),
+        (BECH32, b"hI1dpjkcmr0av0je8", 1),
+        (BECH32, b"hi1dpjkcmr0aV0je8", 12),
+    ] {
+        let err = decode_err(input, cfg);
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index &&
+                    x.byte() == input[index] &&
+                    x.encoding() == cfg.encoding
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // InvalidHrp
+    for (cfg, input, length) in [
+        (BECH32, &b"pzry9x0s0muk"[..], 0),
+        (BECH32, b"1pzry9x0s0muk", 0),
+        (BECH32, b"10a06t8", 0),
+        (BECH32, b"1qzzfhee", 0),
+        (BECH32M, b"qyrz8wqd2c9m", 0),
+        (BECH32M, b"1qyrz8wqd2c9m", 0),
+        (BECH32M, b"16plkw9", 0),
+        (BECH32M, b"1p2gdwpf", 0),
+        (Config::bech32("bc"), b"a12uel5l", 1),
+        (Config::bech32("bc"), b"hi1dpjkcmr0av0je8", 2),
+        (Config::bech32("h"), b"hi1dpjkcmr0av0je8", 2),
+        (Config::bech32("hii"), b"hi1dpjkcmr0av0je8", 2),
+        (
+            BECH32,
+            b"an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+            84,
+        ),
+    ] {
+        let err = decode_err(input, cfg);
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidHrp(x)
+                if x.length() == length && x.expected() == cfg.hrp
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // WrongInputLength
+    for (cfg, input) in [
+        (BECH32, &b"li1dgmt3"[..]),
+        (BECH32M, b"in1muywd"),
+        (BECH32, b"a1"),
+    ] {
+        let err = decode_err(input, cfg);
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongInputLength(x) if x.length() == input.len()
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // ChecksumMismatch
+    for (cfg, input) in [
+        (BECH32, &b"A1G7SGD8"[..]),
+        (BECH32M, b"M1VUXWEZ"),
+        (BECH32, b"hi1dpjkcmr0gsl7u9"),
+        (BECH32M, b"hi1dpjkcmr0av0je8"),
+        (BECH32, b"hi1dpjkcmr0av0je9"),
+    ] {
+        let err = decode_err(input, cfg);
+        assert!(
+            matches!(&err, DecodeError::ChecksumMismatch(x) if x.expected() != x.found()),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+    {
+        let err = decode::<5>(b"hi1dpjkcmr0gsl7u9", BECH32).unwrap_err();
+        let mut expected = [0u8; 4];
+        let mut found = [0u8; 4];
+        for (out, checksum) in [(&mut expected, "av0je8"), (&mut found, "gsl7u9")] {
+            let mut value = 0u32;
+            for b in checksum.bytes() {
+                value = (value << 5) | CHARSET.iter().position(|&c| c == b).unwrap() as u32;
+            }
+            *out = value.to_be_bytes();
+        }
+        assert!(
+            matches!(
+                &err,
+                DecodeError::ChecksumMismatch(x)
+                if x.expected() == expected && x.found() == found
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    // WrongOutputLength
+    for out_len in [0, 4, 6] {
+        let mut buffer = [0u8; 6];
+        let err =
+            call_with_len!(buffer, out_len, decode(b"hi1dpjkcmr0av0je8", BECH32)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::WrongOutputLength(x)
+                if x.expected() == 5 && x.found() == out_len
+            ),
+            "{:?}",
+            err
+        );
+    }
+    for out_len in [0, 16, 18] {
+        let mut buffer = [0u8; 18];
+        let err =
+            call_with_len!(buffer, out_len, encode(b"hello", Config::bech32("hi"))).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                EncodeError::WrongOutputLength(x)
+                if x.expected() == out_len && x.found() == 17
+            ),
+            "{:?}",
+            err
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_bech32_empty_hrp() {
+    let _ = Config::bech32("");
+}
+
+#[test]
+#[should_panic]
+fn test_bech32_long_hrp() {
+    let _ = Config::bech32(
+        "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio",
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_bech32_invalid_hrp_char() {
+    let _ = Config::bech32("a b");
+}
+
+#[test]
+fn test_bech32_encode_without_hrp() {
+    let mut buffer = [0u8; 32];
+    for cfg in [BECH32, BECH32M] {
+        assert_eq!(encoded_len(5, cfg), 15);
+        assert_eq!(
+            encode::<17>(b"hello", cfg),
+            Err(EncodeError::InvalidHrp(InvalidHrp {
+                expected: "",
+                length: 0
+            }))
+        );
+        assert!(matches!(
+            encode_to_slice(b"hello", &mut buffer, cfg),
+            Err(EncodeError::InvalidHrp(x)) if x.length() == 0
+        ));
+    }
+}
+
+#[test]
+fn test_bech32_max_length() {
+    let input = [0xAB; 52];
+    let mut buffer = [0u8; 128];
+
+    // 1 character for the human-readable part, 1 for the separator,
+    // 82 for the data, and 6 for the checksum.
+    let cfg = Config::bech32("a");
+    let len = encode_to_slice(&input[..51], &mut buffer, cfg).unwrap();
+    assert_eq!(len, 90);
+    assert_eq!(decode::<51>(&buffer[..len], cfg).unwrap(), input[..51]);
+
+    // line endings and group separators aren't counted
+    let grouped = cfg.group(4, b' ');
+    let len = encode_to_slice(&input[..51], &mut buffer, grouped).unwrap();
+    assert_eq!(len, 111);
+    assert_eq!(decode::<51>(&buffer[..len], grouped).unwrap(), input[..51]);
+
+    for cfg in [
+        cfg,
+        Config::bech32m("a"),
+        Config::bech32("a").group(4, b' '),
+    ] {
+        assert!(
+            matches!(
+                encode_to_slice(&input, &mut buffer, cfg),
+                Err(EncodeError::WrongInputLength(x))
+                if x.length() == 52 && x.encoding() == cfg.encoding
+            ),
+            "{:?}",
+            cfg.encoding
+        );
+    }
+
+    let mut long = ArrayWriter::new();
+    bech32::encode_lower_to_fmt::<Bech32, _>(&mut long, Hrp::parse("a").unwrap(), &input).unwrap();
+    let long = long.as_bytes();
+    assert_eq!(long.len(), 92);
+
+    for cfg in [cfg, BECH32] {
+        let mut out = [0u8; 52];
+        assert!(
+            matches!(
+                decode_to_slice(long, &mut out, cfg),
+                Err(DecodeError::WrongInputLength(x)) if x.length() == 92
+            ),
+            "{:?}",
+            cfg.hrp
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, BitOrder, Config, DecodeError,
+};
+
+use data_encoding::{BitOrder as DeBitOrder, Specification};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+const B64_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const B32_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const B32_HEX_CHARS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const HEX_CHARS: &str = "0123456789ABCDEF";
+
+fn spec_encoding(symbols: &str, padding: bool) -> data_encoding::Encoding {
+    let mut spec = Specification::new();
+    spec.symbols.push_str(symbols);
+    spec.bit_order = DeBitOrder::LeastSignificantFirst;
+    if padding {
+        spec.padding = Some('=');
+    }
+    spec.encoding().unwrap()
+}
+
+fn cases() -> [(Config, data_encoding::Encoding); 7] {
+    [
+        (Config::B64, spec_encoding(B64_CHARS, true)),
+        (
+            Config::B64.end_padding(false),
+            spec_encoding(B64_CHARS, false),
+        ),
+        (Config::B32, spec_encoding(B32_CHARS, true)),
+        (
+            Config::B32.end_padding(false),
+            spec_encoding(B32_CHARS, false),
+        ),
+        (Config::B32_HEX, spec_encoding(B32_HEX_CHARS, true)),
+        (Config::HEX, spec_encoding(HEX_CHARS, false)),
+        (
+            Config::HEX_LOWER,
+            spec_encoding(&HEX_CHARS.to_ascii_lowercase(), false),
+        ),
+    ]
+}
+
+#[test]
+fn test_lsb_first_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for (cfg, de_encoding) in cases() {
+        let cfg = cfg.bit_order(BitOrder::LsbFirst);
+
+        for in_length in 0..=30 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 30];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let expected = de_encoding.encode(input);
+
+                let enc_len = encoded_len(in_length, cfg);
+                assert_eq!(enc_len, expected.len());
+
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+                assert_eq!(encoded, expected.as_bytes(), "\ninput: {:x?}", input);
+
+                assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                assert_eq!(decoded, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lsb_first_decode_random() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    // compares decoding of arbitrary strings, including those with nonzero excess bits
+    for (cfg, symbols) in [
+        (Config::B64, B64_CHARS),
+        (Config::B32, B32_CHARS),
+        (Config::HEX, HEX_CHARS),
+    ] {
+        let cfg = cfg.end_padding(false).bit_order(BitOrder::LsbFirst);
+        let de_encoding = spec_encoding(symbols, false);
+
+        for in_length in 0..=20 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 20];
+                let input = &mut input[..in_length];
+                for b in &mut *input {
+                    *b = symbols.as_bytes()[rng.gen_range(0..symbols.len())];
+                }
+
+                let expected = de_encoding.decode(input);
+
+                let mut buffer = [0u8; 80];
+                let len = decoded_len(input, cfg);
+                let decoded = call_with_len!(buffer, len, decode(input, cfg));
+
+                match (&expected, decoded) {
+                    (Ok(expected), Ok(decoded)) => assert_eq!(decoded, &expected[..]),
+                    (Err(_), Err(DecodeError::ExcessBits(_)))
+                    | (Err(_), Err(DecodeError::WrongInputLength(_))) => {}
+                    (expected, decoded) => {
+                        panic!("{:?} {:?}\ninput: {:?}", expected, decoded, input)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lsb_first_with_other_settings() {
+    // excess bits are the high bits of the last character
+    let cfg = Config::B64.bit_order(BitOrder::LsbFirst);
+    assert_eq!(encode::<4>(&[0xFF], cfg).unwrap(), "/D==");
+    assert!(matches!(
+        decode::<1>(b"/P==", cfg),
+        Err(DecodeError::ExcessBits(x)) if x.last_byte() == b'P'
+    ));
+    assert_eq!(
+        decode::<1>(b"/P==", cfg.allow_excess_bits(true)).unwrap(),
+        [0xFF]
+    );
+
+    // skipped bytes and grouping are unaffected by the bit order
+    let cfg = Config::HEX.bit_order(BitOrder::LsbFirst).group(2, b':');
+    assert_eq!(encode::<8>(&[0x12, 0x34, 0x56], cfg).unwrap(), "21:43:65");
+    assert_eq!(decode::<3>(b"21:43:65", cfg).unwrap(), [0x12, 0x34, 0x56]);
+
+    // other encodings ignore the bit order
+    for cfg in [Config::B58, Config::B45, Config::ASCII85] {
+        assert_eq!(
+            encode::<3>(b"hi", cfg.bit_order(BitOrder::LsbFirst)).unwrap(),
+            encode::<3>(b"hi", cfg).unwrap(),
+        );
+    }
+    let bech = Config::bech32("a");
+    assert_eq!(
+        encode::<12>(b"hi", bech.bit_order(BitOrder::LsbFirst)).unwrap(),
+        encode::<12>(b"hi", bech).unwrap(),
+    );
+}
@@ -0,0 +1,195 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, B64CharSet, Config,
+    CustomCharSet, DecodeError, Encoding, HexCharSet,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+const BCRYPT: &[u8; 64] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const CRYPT: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+const B32_REVERSED: &[u8; 32] = b"765432ZYXWVUTSRQPONMLKJIHGFEDCBA";
+
+const HEX_SHUFFLED: &[u8; 16] = b"fedcba9876543210";
+
+// translates every character of `encoded` that's in `from`,
+// into the character at the same position in `to`.
+fn translate<'a>(encoded: &[u8], from: &[u8], to: &[u8], buffer: &'a mut [u8]) -> &'a [u8] {
+    let buffer = &mut buffer[..encoded.len()];
+
+    for (out, &b) in buffer.iter_mut().zip(encoded) {
+        *out = match from.iter().position(|&x| x == b) {
+            Some(pos) => to[pos],
+            None => b,
+        };
+    }
+
+    buffer
+}
+
+#[test]
+fn test_custom_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    let cases: [(Config, &[u8], Config, &[u8]); 4] = [
+        (
+            Config::B64,
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Config::custom_b64(BCRYPT),
+            BCRYPT,
+        ),
+        (
+            Config::B64,
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Config::custom_b64(CRYPT),
+            CRYPT,
+        ),
+        (
+            Config::B32,
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Config::custom_b32(B32_REVERSED),
+            B32_REVERSED,
+        ),
+        (
+            Config::HEX_LOWER,
+            b"0123456789abcdef",
+            Config::custom_hex(HEX_SHUFFLED),
+            HEX_SHUFFLED,
+        ),
+    ];
+
+    for in_length in 0..=30 {
+        for (std_cfg, std_chars, custom_cfg, custom_chars) in cases {
+            for pad in [false, true] {
+                let std_cfg = std_cfg.end_padding(pad);
+                let custom_cfg = custom_cfg.end_padding(pad);
+
+                for _ in 0..GEN_ITERS {
+                    let mut input = [0u8; 30];
+                    let input = &mut input[..in_length];
+                    rng.fill(&mut *input);
+
+                    let enc_len = encoded_len(in_length, custom_cfg);
+                    assert_eq!(encoded_len(in_length, std_cfg), enc_len);
+
+                    let mut buffer = [0u8; 80];
+                    let standard = call_with_len!(buffer, enc_len, encode(input, std_cfg)).unwrap();
+
+                    let mut expected = [0u8; 80];
+                    let expected = translate(standard, std_chars, custom_chars, &mut expected);
+
+                    let mut buffer = [0u8; 80];
+                    let encoded =
+                        call_with_len!(buffer, enc_len, encode(input, custom_cfg)).unwrap();
+                    assert_eq!(encoded, expected, "\ninput: {:x?}", input);
+
+                    assert_eq!(decoded_len(encoded, custom_cfg), in_length);
+
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(encoded, custom_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_custom_macros() {
+    const CFG: Config = Config::custom_b64(BCRYPT);
+
+    const ENCODED: &str = crate::encode_as_str!(b"hello", CFG);
+    assert_eq!(ENCODED, "YETqZE6=");
+
+    const DECODED: &[u8] = crate::decode!(ENCODED, CFG);
+    assert_eq!(DECODED, b"hello");
+
+    assert_eq!(
+        CFG.encoding,
+        Encoding::Base64(B64CharSet::Custom(CustomCharSet::new(BCRYPT)))
+    );
+    assert_eq!(CustomCharSet::new(BCRYPT).alphabet(), BCRYPT);
+}
+
+#[test]
+fn test_custom_invalid_byte() {
+    for (cfg, alphabet) in [
+        (Config::custom_b64(BCRYPT), &BCRYPT[..]),
+        (Config::custom_b32(B32_REVERSED), &B32_REVERSED[..]),
+        (Config::custom_hex(HEX_SHUFFLED), &HEX_SHUFFLED[..]),
+    ] {
+        let mut invalid_bytes = crate::test_utils::ByteSet([true; 256]);
+        for &b in alphabet {
+            invalid_bytes.remove(b);
+        }
+
+        for (b, is_invalid) in invalid_bytes.iter() {
+            let mut bytes = [alphabet[0]; 8];
+            bytes[1] = b;
+            let mut buffer = [0u8; 8];
+            let len = decoded_len(&bytes, cfg);
+            let res = call_with_len!(buffer, len, decode(&bytes, cfg));
+
+            if is_invalid {
+                let err = res.unwrap_err();
+                assert!(
+                    matches!(
+                        &err,
+                        DecodeError::InvalidByte(x)
+                        if x.index() == 1 && x.byte() == b && x.encoding() == cfg.encoding
+                    ),
+                    "{:?} {:?}",
+                    err,
+                    cfg.encoding
+                );
+            } else {
+                assert!(
+                    !matches!(res, Err(DecodeError::InvalidByte(_))),
+                    "{:?} {:?}",
+                    res,
+                    b
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_custom_hex_is_case_sensitive() {
+    const CFG: Config = Config::custom_hex(b"0123456789abcdef");
+
+    assert_eq!(decode::<2>(b"beef", CFG).unwrap(), [0xBE, 0xEF]);
+
+    let err = decode::<2>(b"bEEf", CFG).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            DecodeError::InvalidByte(x)
+            if x.index() == 1 && x.encoding() == Encoding::Hex(HexCharSet::Custom(CustomCharSet::new(b"0123456789abcdef")))
+        ),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_custom_duplicate_panics() {
+    let _ = Config::custom_hex(b"0123456789abcde0");
+}
+
+#[test]
+#[should_panic]
+fn test_custom_non_ascii_panics() {
+    let _ = Config::custom_hex(b"0123456789abcde\xFF");
+}
+
+#[test]
+#[should_panic]
+fn test_custom_padding_panics() {
+    let _ = Config::custom_b32(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ23456=");
+}
@@ -0,0 +1,170 @@
+use crate::{encode_to_slice, BitOrder, Config, LineEnding};
+
+use core::fmt::{self, Write};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 3 } else { 30 };
+
+const CONFIGS: &[Config] = &[
+    Config::B64,
+    Config::B64_URL_SAFE.end_padding(false),
+    Config::B64.bit_order(BitOrder::LsbFirst),
+    Config::B64.line_wrap(1, LineEnding::CrLf),
+    Config::B32,
+    Config::B32_CROCKFORD.check_symbol(true),
+    Config::B32.group(3, b' ').line_wrap(7, LineEnding::Lf),
+    Config::HEX,
+    Config::HEX.line_wrap(1, LineEnding::CrLf),
+    Config::HEX_LOWER.hex_prefix("0x"),
+    Config::HEX
+        .hex_byte_prefix("0x")
+        .hex_byte_separator(", ")
+        .line_wrap(5, LineEnding::Lf),
+    Config::HEX
+        .bit_order(BitOrder::LsbFirst)
+        .hex_byte_separator(":"),
+    Config::B58,
+    Config::B58.group(4, b'-'),
+    Config::B45,
+    Config::B45.line_wrap(7, LineEnding::CrLf),
+    Config::ASCII85,
+    Config::ASCII85
+        .delimiters(true)
+        .line_wrap(9, LineEnding::Lf),
+    Config::B85,
+    Config::B32_CROCKFORD.check_symbol(true).group(4, b'-'),
+    Config::bech32("a"),
+    Config::bech32("a").group(5, b' '),
+];
+
+// A `fmt::Write` that writes into a fixed-size buffer
+struct Buffer {
+    bytes: [u8; 1024],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 1024],
+            len: 0,
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_display_matches_encode() {
+    let mut rng = SmallRng::seed_from_u64(4418937526102459213);
+
+    for &cfg in CONFIGS {
+        for in_length in [0, 1, 2, 3, 4, 5, 31, 32, 33, 64, 100] {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 100];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let mut expected = [0u8; 1024];
+                let mut buffer = Buffer::new();
+
+                // bech32 strings are limited to 90 characters,
+                // and base 85 input must be a multiple of 4 bytes long
+                let Ok(len) = encode_to_slice(input, &mut expected, cfg) else {
+                    assert!(write!(buffer, "{}", cfg.display(input)).is_err());
+                    continue;
+                };
+                let expected = &expected[..len];
+
+                write!(buffer, "{}", cfg.display(input)).unwrap();
+                assert_eq!(buffer.as_bytes(), expected, "{:?}", cfg.encoding);
+
+                let mut buffer = Buffer::new();
+                write!(buffer, "{:?}", cfg.display(input)).unwrap();
+                let debug = buffer.as_bytes();
+                assert_eq!(debug[0], b'"');
+                assert_eq!(debug[debug.len() - 1], b'"');
+                if expected.iter().all(u8::is_ascii_alphanumeric) {
+                    assert_eq!(&debug[1..debug.len() - 1], expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_display_debug_escapes() {
+    let mut buffer = Buffer::new();
+    let cfg = Config::HEX.line_wrap(4, LineEnding::CrLf);
+    write!(buffer, "{:?}", cfg.display(b"hello")).unwrap();
+    assert_eq!(buffer.as_bytes(), br#""6865\r\n6C6C\r\n6F""#);
+}
+
+#[test]
+fn test_display_long_input() {
+    // no configuration is limited by the stack buffer
+    let mut buffer = Buffer::new();
+    write!(buffer, "{}", Config::B64.display(&[0; 600])).unwrap();
+    assert_eq!(buffer.as_bytes(), &[b'A'; 800][..]);
+
+    let input = [1u8; 200];
+    let mut expected = [0u8; 1024];
+
+    for cfg in [
+        Config::B58,
+        Config::B45,
+        Config::Z85,
+        Config::HEX.hex_byte_separator(" "),
+    ] {
+        let len = encode_to_slice(&input, &mut expected, cfg).unwrap();
+        let mut buffer = Buffer::new();
+        write!(buffer, "{}", cfg.display(&input)).unwrap();
+        assert_eq!(buffer.as_bytes(), &expected[..len], "{:?}", cfg.encoding);
+    }
+}
+
+#[test]
+fn test_display_writer_error() {
+    let mut buffer = Buffer::new();
+    buffer.len = buffer.bytes.len() - 10;
+    assert!(write!(buffer, "{}", Config::B58.display(&[1; 100])).is_err());
+}
+
+#[test]
+fn test_display_invalid_input_errors() {
+    // Z85 requires the input length to be a multiple of 4
+    let mut buffer = Buffer::new();
+    assert!(write!(buffer, "{}", Config::Z85.display(b"hello")).is_err());
+
+    // base 58 strings are encoded into a 512 byte buffer
+    let mut buffer = Buffer::new();
+    assert!(write!(buffer, "{}", Config::B58.display(&[0xFF; 400])).is_err());
+
+    // only the actual length of the base 58 string is limited
+    let mut buffer = Buffer::new();
+    write!(buffer, "{}", Config::B58.display(&[0; 500])).unwrap();
+    assert_eq!(buffer.as_bytes(), &[b'1'; 500][..]);
+}
+
+#[test]
+fn test_display_accessors() {
+    let encoded = Config::B32.display(b"foo");
+    assert_eq!(encoded.input(), b"foo");
+    assert_eq!(encoded.config().encoding, Config::B32.encoding);
+}
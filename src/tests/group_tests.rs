@@ -0,0 +1,270 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, encoded_len_of, test_utils::AsBytes, Config,
+    DecodeError, LineEnding,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+// inserts `separator` after every `size` characters of `encoded[start..end]`,
+// except at the end of that range.
+fn group<'a>(
+    encoded: &[u8],
+    (start, end): (usize, usize),
+    size: usize,
+    separator: u8,
+    buffer: &'a mut [u8],
+) -> &'a [u8] {
+    let mut len = 0;
+    for (i, &b) in encoded.iter().enumerate() {
+        if i > start && i < end && (i - start) % size == 0 {
+            buffer[len] = separator;
+            len += 1;
+        }
+        buffer[len] = b;
+        len += 1;
+    }
+    &buffer[..len]
+}
+
+// The range of the characters that are grouped
+fn grouped_range(encoded: &[u8], cfg: Config) -> (usize, usize) {
+    match cfg.encoding {
+        crate::Encoding::Bech32(_) => {
+            let sep = encoded.iter().rposition(|&b| b == b'1').unwrap();
+            (sep + 1, encoded.len())
+        }
+        _ if cfg.has_delimiters() => (2, encoded.len() - 2),
+        _ => (0, encoded.len()),
+    }
+}
+
+#[test]
+fn test_group_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=12 {
+        for (cfg, separators) in [
+            (Config::B64, &b"-: "[..]),
+            (Config::B64.end_padding(false), b"-: "),
+            (Config::B32, b"-: "),
+            (Config::B32_CROCKFORD.check_symbol(true), b"-: "),
+            (Config::HEX, b"-: "),
+            (Config::B58, b"-: "),
+            (Config::B58.checksum(true), b"-: "),
+            (Config::B45, b"_,"),
+            (Config::ASCII85, b" ~"),
+            (Config::ASCII85.delimiters(true), b" \n"),
+            (Config::Z85, b" ~"),
+            (Config::bech32("bc"), b"-: "),
+            (Config::bech32m("a"), b"-: "),
+        ] {
+            if matches!(
+                cfg.encoding,
+                crate::Encoding::Base85(crate::B85CharSet::Z85)
+            ) && in_length % 4 != 0
+            {
+                continue;
+            }
+
+            for &separator in separators {
+                let size = rng.gen_range(1..=6);
+                let grouped_cfg = cfg.group(size, separator);
+
+                for _ in 0..GEN_ITERS {
+                    let mut input = [0u8; 12];
+                    let input = &mut input[..in_length];
+                    rng.fill(&mut *input);
+                    if rng.gen_bool(0.2) {
+                        input[..in_length / 2].fill(0);
+                    }
+
+                    let ungrouped_len = encoded_len_of(input, cfg);
+                    let mut buffer = [0u8; 80];
+                    let ungrouped =
+                        call_with_len!(buffer, ungrouped_len, encode(input, cfg)).unwrap();
+
+                    let mut expected = [0u8; 200];
+                    let range = grouped_range(ungrouped, cfg);
+                    let expected = group(ungrouped, range, size, separator, &mut expected);
+
+                    let enc_len = encoded_len_of(input, grouped_cfg);
+                    assert_eq!(enc_len, expected.len());
+
+                    let mut buffer = [0u8; 80];
+                    let encoded =
+                        call_with_len!(buffer, enc_len, encode(input, grouped_cfg)).unwrap();
+                    assert_eq!(
+                        encoded, expected,
+                        "\nsize: {} {:?}",
+                        size, grouped_cfg.encoding
+                    );
+
+                    assert_eq!(decoded_len(encoded, grouped_cfg), in_length);
+
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(encoded, grouped_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+
+                    // the ungrouped string is also accepted
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(ungrouped, grouped_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_group_encoded_len() {
+    // `encoded_len` is the maximum length, which includes the separators
+    for cfg in [Config::B58, Config::ASCII85] {
+        for len in 0..=20 {
+            let ungrouped = encoded_len(len, cfg);
+            let expected = ungrouped + ungrouped.saturating_sub(1) / 3;
+            assert_eq!(encoded_len(len, cfg.group(3, b' ')), expected);
+        }
+    }
+
+    assert_eq!(encoded_len(0, Config::HEX.group(2, b':')), 0);
+    assert_eq!(encoded_len(1, Config::HEX.group(2, b':')), 2);
+    assert_eq!(encoded_len(4, Config::HEX.group(2, b':')), 11);
+}
+
+#[test]
+fn test_group_macros() {
+    const CFG: Config = Config::HEX.group(2, b':');
+
+    const ENCODED: &str = crate::encode_as_str!(&[0x00, 0x1A, 0xFF], CFG);
+    assert_eq!(ENCODED, "00:1A:FF");
+
+    const DECODED: &[u8] = crate::decode!("00:1a:ff", CFG);
+    assert_eq!(DECODED, [0x00, 0x1A, 0xFF]);
+
+    const B32: &str = crate::encode_as_str!(b"hi", Config::B32.group(4, b' '));
+    assert_eq!(B32, "NBUQ ====");
+
+    const BECH32: &str = crate::encode_as_str!(b"hi", Config::bech32("b-c").group(4, b'-'));
+    assert_eq!(&BECH32[..4], "b-c1");
+    assert_eq!(BECH32[4..].split('-').count(), 3);
+    assert_eq!(
+        crate::decode!(BECH32, Config::bech32("b-c").group(4, b'-')),
+        b"hi"
+    );
+
+    const ASCII85: &str =
+        crate::encode_as_str!(b"hello", Config::ASCII85.delimiters(true).group(3, b' '));
+    assert_eq!(ASCII85, "<~BOu !rD Z~>");
+}
+
+#[test]
+fn test_group_with_line_wrap() {
+    let cfg = Config::HEX.group(2, b' ').line_wrap(6, LineEnding::Lf);
+
+    let input = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD];
+    let encoded = encode::<20>(&input, cfg).unwrap();
+    assert_eq!(encoded, "01 23 45\n67 89 AB\nCD");
+    assert_eq!(encoded_len(input.len(), cfg), 20);
+
+    assert_eq!(decode::<7>(encoded.as_bytes(), cfg).unwrap(), input);
+
+    // groups are counted from the start of the string, not of each line
+    let cfg = Config::HEX.group(4, b'-').line_wrap(6, LineEnding::CrLf);
+    let encoded = encode::<16>(&input[..6], cfg).unwrap();
+    assert_eq!(encoded, "0123-45\r\n67-89AB");
+    assert_eq!(encoded_len(6, cfg), 16);
+}
+
+#[test]
+fn test_group_errors() {
+    // InvalidByte and ChunkOverflow report the offset in the original string
+    for (cfg, input, index) in [
+        (Config::B64, &b"Zm9v-Zm9v-Z!9v"[..], 11),
+        (Config::HEX, b"00:11:2x", 7),
+        (Config::B58, b"2N-Ed-x*", 7),
+        (Config::B45, b"BB8_Q=", 5),
+        (Config::ASCII85, b"BOu !r D{", 8),
+        (Config::bech32("a"), b"a1lq-qqq-qq2-b", 13),
+    ] {
+        let cfg = cfg_with_separator(cfg, input);
+
+        let mut buffer = [0u8; 16];
+        let len = decoded_len(input, cfg);
+        let err = call_with_len!(buffer, len, decode(input, cfg)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index && x.byte() == input[index]
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // base 45 chunks that overflow are reported at their first character
+    let err = decode::<2>(b"G_GW", Config::B45.group(1, b'_')).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::ChunkOverflow(x) if x.index() == 0),
+        "{:?}",
+        err
+    );
+
+    let err = decode::<7>(b"s8 W- s8 W-!", Config::ASCII85.group(2, b' ')).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::ChunkOverflow(x) if x.index() == 0),
+        "{:?}",
+        err
+    );
+}
+
+// Uses the first non-alphanumeric character of `input` as the separator
+fn cfg_with_separator(cfg: Config, input: &[u8]) -> Config {
+    let sep = *input
+        .iter()
+        .find(|&&b| matches!(b, b'-' | b':' | b' ' | b'_'))
+        .unwrap();
+    cfg.group(3, sep)
+}
+
+#[test]
+#[should_panic]
+fn test_group_zero_size_panics() {
+    let _ = Config::HEX.group(0, b':');
+}
+
+#[test]
+#[should_panic]
+fn test_group_in_alphabet_panics() {
+    let _ = Config::B64.group(4, b'+');
+}
+
+#[test]
+#[should_panic]
+fn test_group_padding_panics() {
+    let _ = Config::B32.group(4, b'=');
+}
+
+#[test]
+#[should_panic]
+fn test_group_non_ascii_panics() {
+    let _ = Config::HEX.group(2, 0xFF);
+}
+
+#[test]
+#[should_panic]
+fn test_group_bech32_separator_panics() {
+    let _ = Config::bech32("bc").group(4, b'1');
+}
+
+#[test]
+#[should_panic]
+fn test_group_ascii85_zero_chunk_panics() {
+    let _ = Config::ASCII85.group(4, b'z');
+}
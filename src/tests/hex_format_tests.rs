@@ -0,0 +1,199 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, BitOrder, Config, DecodeError,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+// formats `input` as hexadecimal with the prefixes and separator
+fn format_hex<'a>(
+    input: &[u8],
+    (prefix, byte_prefix, separator): (&str, &str, &str),
+    buffer: &'a mut [u8],
+) -> &'a mut [u8] {
+    let mut len = 0;
+    let mut push = |bytes: &[u8]| {
+        buffer[len..len + bytes.len()].copy_from_slice(bytes);
+        len += bytes.len();
+    };
+
+    push(prefix.as_bytes());
+    for (i, &b) in input.iter().enumerate() {
+        if i != 0 {
+            push(separator.as_bytes());
+        }
+        push(byte_prefix.as_bytes());
+        push(&[
+            HEX_DIGITS[(b >> 4) as usize],
+            HEX_DIGITS[(b & 0xF) as usize],
+        ]);
+    }
+    &mut buffer[..len]
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+#[test]
+fn test_hex_format_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for format @ (prefix, byte_prefix, separator) in [
+        ("0x", "", ""),
+        ("", "0x", ", "),
+        ("", "", " "),
+        ("", "\\x", ""),
+        ("[", "$", ","),
+    ] {
+        let cfg = Config::HEX
+            .hex_prefix(prefix)
+            .hex_byte_prefix(byte_prefix)
+            .hex_byte_separator(separator);
+
+        for in_length in 0..=10 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 10];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let mut expected = [0u8; 80];
+                let expected = format_hex(input, format, &mut expected);
+
+                let enc_len = encoded_len(in_length, cfg);
+                assert_eq!(enc_len, expected.len());
+
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+                assert_eq!(encoded, &expected[..]);
+
+                assert_eq!(decoded_len(encoded, cfg), in_length);
+
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(encoded, cfg)).unwrap();
+                assert_eq!(decoded, input);
+
+                // prefixes are case-insensitive by default
+                expected.make_ascii_lowercase();
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(&*expected, cfg)).unwrap();
+                assert_eq!(decoded, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hex_format_macros() {
+    const CFG: Config = Config::HEX.hex_byte_prefix("0x").hex_byte_separator(", ");
+
+    const ENCODED: &str = crate::encode_as_str!(&[0xDE, 0xAD, 0xBE, 0xEF], CFG);
+    assert_eq!(ENCODED, "0xDE, 0xAD, 0xBE, 0xEF");
+
+    const DECODED: &[u8] = crate::decode!("0xDE, 0xAD, 0xBE, 0xEF", CFG);
+    assert_eq!(DECODED, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+    const LOWER: &str = crate::encode_as_str!(&[0xDE, 0xAD], Config::HEX_LOWER.hex_prefix("0x"));
+    assert_eq!(LOWER, "0xdead");
+}
+
+#[test]
+fn test_hex_format_errors() {
+    let cfg = Config::HEX.hex_byte_prefix("0x").hex_byte_separator(", ");
+
+    for (input, index) in [
+        (&b"0xDE, 1xAD"[..], 6),
+        (b"0xDE, 0yAD", 7),
+        (b"0xDE; 0xAD", 4),
+        (b"0xDE,0xAD", 5),
+        (b"DE, 0xAD", 0),
+        (b"0xDG, 0xAD", 3),
+        (b"0xDE0xAD", 4),
+    ] {
+        let err = decode::<2>(input, cfg).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index && x.byte() == input[index]
+            ),
+            "{:?} {:?}",
+            err,
+            core::str::from_utf8(input).unwrap(),
+        );
+    }
+
+    // a separator after the last byte is an incomplete byte
+    for input in [&b"0xDE, 0xAD, "[..], b"0xDE, 0xA", b"0xDE, 0x"] {
+        let err = decode::<2>(input, cfg).unwrap_err();
+        assert!(
+            matches!(&err, DecodeError::WrongInputLength(x) if x.length() == input.len()),
+            "{:?}",
+            err,
+        );
+    }
+
+    // an incomplete string prefix
+    let err = decode::<0>(b"0", Config::HEX.hex_prefix("0x")).unwrap_err();
+    assert!(matches!(err, DecodeError::WrongInputLength(_)), "{:?}", err);
+
+    let err = decode::<1>(b"0X12", Config::HEX.hex_prefix("0x").strict_case(true)).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 1),
+        "{:?}",
+        err,
+    );
+
+    // the output length is checked against the amount of decoded bytes
+    let err = decode::<3>(b"0xDE, 0xAD", cfg).unwrap_err();
+    assert!(
+        matches!(err, DecodeError::WrongOutputLength(_)),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_hex_format_with_other_settings() {
+    // groups are inserted between the characters after the string prefix
+    let cfg = Config::HEX.hex_prefix("0x").group(4, b'_');
+    let encoded = encode::<14>(&[0x01, 0x23, 0x45, 0x67, 0x89], cfg).unwrap();
+    assert_eq!(encoded, "0x0123_4567_89");
+    assert_eq!(encoded_len(5, cfg), 14);
+    assert_eq!(
+        decode::<5>(encoded.as_bytes(), cfg).unwrap(),
+        [0x01, 0x23, 0x45, 0x67, 0x89]
+    );
+
+    // the bit order only swaps the digits
+    let cfg = Config::HEX
+        .bit_order(BitOrder::LsbFirst)
+        .hex_byte_prefix("0x")
+        .hex_byte_separator(" ");
+    let encoded = encode::<9>(&[0x12, 0x34], cfg).unwrap();
+    assert_eq!(encoded, "0x21 0x43");
+    assert_eq!(decode::<2>(encoded.as_bytes(), cfg).unwrap(), [0x12, 0x34]);
+
+    // ignored bytes are skipped outside of the prefixes and separators
+    let cfg = Config::HEX.hex_byte_separator(",").ignore_bytes(b" \n");
+    assert_eq!(
+        decode::<3>(b"12, 34,\n 5 6", cfg).unwrap(),
+        [0x12, 0x34, 0x56]
+    );
+
+    // other encodings ignore the hex formatting
+    let cfg = Config::B64.hex_prefix("0x").hex_byte_separator(", ");
+    assert_eq!(encode::<4>(b"hi", cfg).unwrap(), "aGk=");
+}
+
+#[test]
+#[should_panic]
+fn test_hex_prefix_non_ascii_panics() {
+    let _ = Config::HEX.hex_prefix("ñ");
+}
+
+#[test]
+#[should_panic]
+fn test_hex_byte_separator_non_ascii_panics() {
+    let _ = Config::HEX.hex_byte_separator(" ・ ");
+}
@@ -0,0 +1,158 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, Config, DecodeError, PaddingMode,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+const WHITESPACE: &[u8] = b" \t\r\n";
+
+#[test]
+fn test_ignore_bytes_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=20 {
+        for cfg in [
+            Config::B64,
+            Config::B64_URL_SAFE.end_padding(false),
+            Config::B32,
+            Config::B32_CROCKFORD,
+            Config::HEX,
+            Config::HEX_LOWER,
+        ] {
+            let cfg = cfg.ignore_bytes(WHITESPACE);
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 20];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let enc_len = encoded_len(in_length, cfg);
+                let mut buffer = [0u8; 80];
+                let encoded = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                // inserts whitespace between (and around) the encoded characters
+                let mut spaced = [0u8; 200];
+                let mut spaced_len = 0;
+                for &b in encoded.iter().chain([b'\n'].iter()) {
+                    while rng.gen_bool(0.3) {
+                        spaced[spaced_len] = WHITESPACE[rng.gen_range(0..WHITESPACE.len())];
+                        spaced_len += 1;
+                    }
+                    spaced[spaced_len] = b;
+                    spaced_len += 1;
+                }
+                let spaced = &spaced[..spaced_len];
+
+                assert_eq!(decoded_len(spaced, cfg), in_length);
+
+                let mut buffer = [0u8; 80];
+                let decoded = call_with_len!(buffer, in_length, decode(spaced, cfg)).unwrap();
+                assert_eq!(decoded, input, "\nspaced: {:?}", spaced);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ignore_bytes_macros() {
+    const CFG: Config = Config::B64.ignore_bytes(b"\n");
+
+    const DECODED: &[u8] = crate::decode!("SGVs\nbG8s\nIHdv\ncmxk\nIQ=\n=\n", CFG);
+    assert_eq!(DECODED, b"Hello, world!");
+
+    const HEX: &[u8] = crate::decode!("de ad be ef", Config::HEX.ignore_bytes(b" "));
+    assert_eq!(HEX, [0xDE, 0xAD, 0xBE, 0xEF]);
+}
+
+#[test]
+fn test_ignore_bytes_padding_mode() {
+    let cfg = Config::B64
+        .ignore_bytes(b"\n")
+        .padding_mode(PaddingMode::Required);
+
+    assert_eq!(decode::<1>(b"Zg\n==\n", cfg).unwrap(), *b"f");
+    assert_eq!(decode::<1>(b"Z\ng=\n=", cfg).unwrap(), *b"f");
+
+    let err = decode::<1>(b"Z\ng\n=", cfg).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidPadding(x) if x.expected() == 2 && x.found() == 1),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_ignore_bytes_errors() {
+    // InvalidByte reports the offset in the original string
+    for (cfg, input, index) in [
+        (Config::B64, &b"Zm9v\nZm9v\nZ!9v"[..], 11),
+        (Config::B32, b"MZ\nX*6\n===", 4),
+        (Config::HEX, b"00 11 22 3x", 10),
+        (Config::HEX, b"00 11\t2", 5),
+    ] {
+        let cfg = cfg.ignore_bytes(b" \n");
+
+        let mut buffer = [0u8; 16];
+        let len = decoded_len(input, cfg);
+        let err = call_with_len!(buffer, len, decode(input, cfg)).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                DecodeError::InvalidByte(x)
+                if x.index() == index && x.byte() == input[index]
+            ),
+            "{:?} {:?}",
+            err,
+            input
+        );
+    }
+
+    // WrongInputLength reports the amount of significant characters
+    let err = decode::<1>(b"0 0 0", Config::HEX.ignore_bytes(b" ")).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::WrongInputLength(x) if x.length() == 3),
+        "{:?}",
+        err
+    );
+
+    // encodings that don't support ignoring bytes don't skip them
+    let err = decode::<5>(b"BOu!r DZ", Config::ASCII85.ignore_bytes(b" ")).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 5),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_ignore_bytes_in_alphabet_panics() {
+    let _ = Config::HEX.ignore_bytes(b" a");
+}
+
+#[test]
+#[should_panic]
+fn test_ignore_bytes_padding_panics() {
+    let _ = Config::B32.ignore_bytes(b"=");
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_ignored_panics() {
+    let _ = Config::B32.ignore_bytes(b".").padding_char(b'.');
+}
+
+#[test]
+#[should_panic]
+fn test_case_insensitive_ignore_bytes_panics() {
+    let _ = Config::B32.ignore_bytes(b"a").case_insensitive(true);
+}
+
+#[test]
+#[should_panic]
+fn test_case_insensitive_group_separator_panics() {
+    let _ = Config::B32.group(4, b'a').case_insensitive(true);
+}
@@ -0,0 +1,170 @@
+use crate::{
+    decode_to_slice, encode_to_slice, encoded_len, BitOrder, Config, Decoder, Encoder, LineEnding,
+    PaddingMode,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 5 } else { 100 };
+
+const CONFIGS: &[Config] = &[
+    Config::B64,
+    Config::B64_URL_SAFE.end_padding(false),
+    Config::B64.bit_order(BitOrder::LsbFirst),
+    Config::B64.line_wrap(5, LineEnding::CrLf),
+    Config::B32,
+    Config::B32_HEX.end_padding(false),
+    Config::B32_CROCKFORD.group(4, b'-'),
+    Config::Z_BASE32.bit_order(BitOrder::LsbFirst),
+    Config::B32.group(3, b' ').line_wrap(7, LineEnding::Lf),
+    Config::HEX,
+    Config::HEX_LOWER.bit_order(BitOrder::LsbFirst),
+    Config::HEX.group(2, b':'),
+];
+
+// Splits `input` into pieces of random lengths, calling `f` with each piece.
+fn for_each_piece(input: &[u8], rng: &mut SmallRng, mut f: impl FnMut(&[u8])) {
+    let mut rem = input;
+    while !rem.is_empty() {
+        let len = rng.gen_range(0..=rem.len().min(7));
+        f(&rem[..len]);
+        rem = &rem[len..];
+    }
+}
+
+#[test]
+fn test_incremental_random_chunking() {
+    let mut rng = SmallRng::seed_from_u64(7316095024468291851);
+
+    for &cfg in CONFIGS {
+        for in_length in 0..=30 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 30];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                let mut expected = [0u8; 128];
+                let enc_len = encode_to_slice(input, &mut expected, cfg).unwrap();
+                let expected = &expected[..enc_len];
+
+                let mut encoded = [0u8; 128];
+                let mut len = 0;
+                let mut encoder = Encoder::new(cfg);
+                for_each_piece(input, &mut rng, |piece| {
+                    let feed_len = encoder.feed_len(piece.len());
+                    assert_eq!(encoder.feed(piece, &mut encoded[len..]), feed_len);
+                    len += feed_len;
+                });
+                let finish_len = encoder.finish_len();
+                assert_eq!(encoder.finish(&mut encoded[len..]), finish_len);
+                len += finish_len;
+
+                assert_eq!(&encoded[..len], expected, "{:?}", cfg);
+
+                // `feed_len` is an upper bound that counts padding and skipped bytes
+                let mut decoded = [0u8; 80];
+                let mut len = 0;
+                let mut decoder = Decoder::new(cfg);
+                for_each_piece(expected, &mut rng, |piece| {
+                    let max_len = decoder.feed_len(piece.len());
+                    let written = decoder.feed(piece, &mut decoded[len..]).unwrap();
+                    assert!(written <= max_len);
+                    len += written;
+                });
+                decoder.finish().unwrap();
+
+                assert_eq!(&decoded[..len], input, "{:?}", cfg);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_incremental_decode_errors() {
+    let mut rng = SmallRng::seed_from_u64(1592043868025349811);
+
+    for (cfg, input) in [
+        (Config::B64, &b"aGVsbG8gd29ybGQ!"[..]),
+        (Config::B64, b"Zg==Zg=="),
+        (Config::B64, b"aGVsbG8gd29ybGQhIQC"),
+        (Config::B64, b"aGVsbG8gd29ybGQhIR=="),
+        (Config::B64, b"AAAAA"),
+        (
+            Config::B64.padding_mode(PaddingMode::Required),
+            b"aGVsbG8gd29ybGQhIQ",
+        ),
+        (Config::B64.padding_mode(PaddingMode::Forbidden), b"Zg=="),
+        (Config::B64.allow_excess_bits(true), b"aGVsbG8gd29ybGQhIR=="),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSA!==="),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSAX"),
+        (Config::B32, b"MY======MY"),
+        (Config::B64, b"Zg==!AAA"),
+        (Config::B64, b"p=\x9e="),
+        (Config::HEX, b"0123456789ABCDEF!0"),
+        (Config::HEX, b"0123456789ABCDEF0"),
+        (Config::HEX.strict_case(true), b"0123456789abcdef"),
+    ] {
+        let mut out = [0u8; 20];
+        let expected = decode_to_slice(input, &mut out, cfg);
+
+        for _ in 0..GEN_ITERS {
+            let mut decoder = Decoder::new(cfg);
+            let mut len = 0;
+            let mut res = Ok(());
+
+            for_each_piece(input, &mut rng, |piece| {
+                if res.is_ok() {
+                    match decoder.feed(piece, &mut out[len..]) {
+                        Ok(written) => len += written,
+                        Err(e) => res = Err(e),
+                    }
+                }
+            });
+
+            let res = res.and_then(|_| decoder.finish()).map(|_| len);
+            assert_eq!(res, expected, "{:?}", input);
+        }
+    }
+}
+
+#[test]
+fn test_incremental_lengths() {
+    let cfg = Config::B64.line_wrap(4, LineEnding::CrLf);
+    let mut encoder = Encoder::new(cfg);
+    let mut out = [0u8; 64];
+
+    assert_eq!(encoder.feed_len(3), 4);
+    assert_eq!(encoder.feed(b"hel", &mut out), 4);
+    assert_eq!(&out[..4], b"aGVs");
+
+    // the line ending comes before the first character of the second line
+    assert_eq!(encoder.feed_len(1), 3);
+    assert_eq!(encoder.feed(b"l", &mut out), 3);
+    assert_eq!(&out[..3], b"\r\nb");
+
+    assert_eq!(encoder.finish_len(), 3);
+    assert_eq!(encoder.finish(&mut out), 3);
+    assert_eq!(&out[..3], b"A==");
+
+    for len in 0..20 {
+        let mut encoder = Encoder::new(cfg);
+        let feed_len = encoder.feed_len(len);
+        encoder.feed(&[0; 20][..len], &mut out);
+        assert_eq!(feed_len + encoder.finish_len(), encoded_len(len, cfg));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_incremental_unsupported_config() {
+    Encoder::new(Config::B58);
+}
+
+#[test]
+#[should_panic]
+fn test_incremental_short_output() {
+    Decoder::new(Config::B64)
+        .feed(b"aGVsbG8=", &mut [0u8; 5])
+        .unwrap();
+}
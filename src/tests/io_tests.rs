@@ -0,0 +1,355 @@
+use crate::{
+    B85CharSet, Config, DecodeError, DecoderReader, EncodeError, EncoderWriter, Encoding,
+    LineEnding, PaddingMode,
+};
+
+use alloc::{string::ToString, vec, vec::Vec};
+
+use std::io::{self, Read, Write};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 5 } else { 50 };
+
+const CONFIGS: &[Config] = &[
+    Config::B64,
+    Config::B64_URL_SAFE.end_padding(false),
+    Config::B32,
+    Config::B32_CROCKFORD,
+    Config::B32_CROCKFORD.check_symbol(true),
+    Config::HEX,
+    Config::HEX_LOWER.hex_prefix("0x"),
+    Config::B58,
+    Config::B45,
+    Config::ASCII85,
+    Config::B85,
+    Config::Z85,
+    Config::bech32("bc"),
+    Config::B64.line_wrap(7, LineEnding::CrLf),
+    Config::B32.group(3, b'-').line_wrap(10, LineEnding::Lf),
+    Config::B45.group(4, b'_'),
+];
+
+// A reader that returns the bytes of `bytes` in pieces of random lengths
+struct ChunkedReader<'a> {
+    bytes: &'a [u8],
+    rng: SmallRng,
+}
+
+impl Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if !self.bytes.is_empty() && self.rng.gen_bool(0.1) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let len = self.rng.gen_range(1..=buf.len()).min(self.bytes.len());
+
+        let (read, rem) = self.bytes.split_at(len);
+        buf[..len].copy_from_slice(read);
+        self.bytes = rem;
+        Ok(len)
+    }
+}
+
+fn is_z85(cfg: Config) -> bool {
+    matches!(cfg.encoding, Encoding::Base85(B85CharSet::Z85))
+}
+
+fn encode_in_pieces(input: &[u8], cfg: Config, rng: &mut SmallRng) -> io::Result<Vec<u8>> {
+    let mut writer = EncoderWriter::new(Vec::new(), cfg);
+
+    let mut rem = input;
+    while !rem.is_empty() {
+        let len = rng.gen_range(1..=rem.len().min(9));
+        writer.write_all(&rem[..len])?;
+        rem = &rem[len..];
+    }
+
+    writer.finish()
+}
+
+fn decode_in_pieces(encoded: &[u8], cfg: Config, rng: &mut SmallRng) -> io::Result<Vec<u8>> {
+    let reader = ChunkedReader {
+        bytes: encoded,
+        rng: SmallRng::seed_from_u64(rng.gen()),
+    };
+
+    let mut out = Vec::new();
+    let mut reader = DecoderReader::new(reader, cfg);
+    let mut buffer = [0u8; 7];
+    loop {
+        let len = rng.gen_range(1..=buffer.len());
+        match reader.read(&mut buffer[..len]) {
+            Ok(0) => break Ok(out),
+            Ok(n) => out.extend_from_slice(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => break Err(e),
+        }
+    }
+}
+
+fn unwrap_decode_error(err: io::Error) -> DecodeError {
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    *err.into_inner().unwrap().downcast::<DecodeError>().unwrap()
+}
+
+#[test]
+fn test_io_matches_alloc() {
+    let mut rng = SmallRng::seed_from_u64(5029484736251099377);
+
+    for &cfg in CONFIGS {
+        for in_length in 0..=40 {
+            if is_z85(cfg) && in_length % 4 != 0 {
+                continue;
+            }
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 40];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+                if rng.gen_bool(0.2) {
+                    input[..in_length / 2].fill(0);
+                }
+
+                let expected = cfg.encode_to_string(input).unwrap();
+
+                let encoded = encode_in_pieces(input, cfg, &mut rng).unwrap();
+                assert_eq!(encoded, expected.as_bytes(), "{:?}", cfg.encoding);
+
+                let decoded = decode_in_pieces(&encoded, cfg, &mut rng).unwrap();
+                assert_eq!(decoded, input, "{:?}", cfg.encoding);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_io_decode_errors() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for (cfg, input) in [
+        (Config::B64, &b"aGVsbG8gd29ybGQ!"[..]),
+        (Config::B64, b"aGVs\nbG8g\nd29y\nbG!Q"),
+        (Config::B64, b"Zg==Zg=="),
+        (Config::B64, b"aGVsbG8gd29ybGQhIQC"),
+        (Config::B64, b"aGVsbG8gd29ybGQhIR=="),
+        (
+            Config::B64.padding_mode(PaddingMode::Required),
+            b"aGVsbG8gd29ybGQhIQ",
+        ),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSA!==="),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSAX"),
+        (Config::B32_CROCKFORD.check_symbol(true), b"D1JPRV3FEGU"),
+        (Config::HEX, b"0123456789ABCDEF0123456789ABCDEF!"),
+        (Config::HEX, b"0123456789ABCDEF0123456789ABCDEF0"),
+        (Config::B45, b"000000000000GGW"),
+        (Config::Z85, b"HelloWorldHelloWorl"),
+        (Config::Z85, b"HelloWorld~elloWorld"),
+        (Config::ASCII85, b"<~s8W-!s8W-!s8W-\"~>"),
+        (Config::bech32("a"), b"a12uel5m"),
+    ] {
+        let expected = cfg.decode_to_vec(input).unwrap_err();
+
+        for _ in 0..GEN_ITERS {
+            let err = decode_in_pieces(input, cfg, &mut rng).unwrap_err();
+            assert_eq!(unwrap_decode_error(err), expected, "{:?}", input);
+        }
+    }
+}
+
+#[test]
+fn test_io_decode_trailing_padding() {
+    let mut rng = SmallRng::seed_from_u64(1190583466028871306);
+
+    // the padding, and the line endings or separators around it,
+    // aren't decoded until the end of the encoded string
+    for (cfg, input) in [
+        (Config::B64, &b"aGVsbG8gd29ybGQ======"[..]),
+        (Config::B64, b"aGVsbG8gd29ybGQ=\n="),
+        (Config::B64, b"aGVsbG8gd29ybGQ=AAAA"),
+        (Config::B64.group(3, b'\t'), b"ugA=="),
+        (Config::B64.group(3, b'\t'), b"aGV\tsbG\t8=\t==="),
+        (
+            Config::B64.line_wrap(4, LineEnding::CrLf),
+            b"aGVs\r\nbG8=\r\n==",
+        ),
+        (Config::B32.line_wrap(7, LineEnding::CrLf), b"ABIQ===\r=="),
+        (
+            Config::B32.line_wrap(7, LineEnding::CrLf),
+            b"ABIQ===\r\n===",
+        ),
+        (Config::B32.group(4, b'-'), b"NBSW-Y3DP-FQ==-====-==="),
+        (
+            Config::B32.padding_mode(PaddingMode::Required),
+            b"NBSWY3DPFQ======A",
+        ),
+    ] {
+        let expected = cfg.decode_to_vec(input);
+
+        for _ in 0..GEN_ITERS {
+            let res = decode_in_pieces(input, cfg, &mut rng).map_err(unwrap_decode_error);
+            assert_eq!(res, expected, "{:?}", input);
+        }
+    }
+}
+
+#[test]
+fn test_io_long_inputs() {
+    let mut rng = SmallRng::seed_from_u64(2817450374810295843);
+
+    let mut input = vec![0u8; if cfg!(miri) { 100 } else { 5000 }];
+    rng.fill(&mut input[10..]);
+
+    for cfg in [
+        Config::B64.line_wrap(76, LineEnding::CrLf),
+        Config::B32,
+        Config::HEX,
+        Config::B58,
+        Config::Z85,
+    ] {
+        let expected = cfg.encode_to_string(&input).unwrap();
+
+        let mut writer = EncoderWriter::new(Vec::new(), cfg);
+        writer.write_all(&input).unwrap();
+        let encoded = writer.finish().unwrap();
+        assert_eq!(encoded, expected.as_bytes());
+
+        let mut decoded = Vec::new();
+        DecoderReader::new(&encoded[..], cfg)
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    // the offset of the invalid byte is from the start of the stream
+    let mut encoded = Config::B64.encode_to_string(&input).unwrap().into_bytes();
+    let last = encoded.len() - 5;
+    encoded[last] = b'!';
+
+    let err = DecoderReader::new(&encoded[..], Config::B64)
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+    match unwrap_decode_error(err) {
+        DecodeError::InvalidByte(err) => assert_eq!(err.index(), last),
+        err => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_io_encode_writes_complete_chars() {
+    let mut writer = EncoderWriter::new(Vec::new(), Config::B64.line_wrap(4, LineEnding::Lf));
+
+    writer.write_all(b"h").unwrap();
+    assert_eq!(writer.get_ref(), b"a");
+
+    writer.write_all(b"ello").unwrap();
+    assert_eq!(writer.get_ref(), b"aGVs\nbG");
+
+    writer.write_all(b" world").unwrap();
+    assert_eq!(writer.get_ref(), b"aGVs\nbG8g\nd29y\nbG");
+
+    writer.flush().unwrap();
+    assert_eq!(writer.get_ref(), b"aGVs\nbG8g\nd29y\nbG");
+
+    assert_eq!(writer.finish().unwrap(), b"aGVs\nbG8g\nd29y\nbGQ=");
+
+    // the configurations with prefixes, suffixes, or check symbols are encoded as they're written
+    let mut writer = EncoderWriter::new(Vec::new(), Config::ASCII85.delimiters(true));
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(writer.get_ref(), b"<~BOu!r");
+    assert_eq!(writer.finish().unwrap(), b"<~BOu!rDZ~>");
+
+    let mut writer = EncoderWriter::new(Vec::new(), Config::HEX_LOWER.hex_prefix("0x"));
+    writer.write_all(&[0xBE, 0xEF]).unwrap();
+    assert_eq!(writer.get_ref(), b"0xbeef");
+    assert_eq!(writer.finish().unwrap(), b"0xbeef");
+
+    let mut writer = EncoderWriter::new(Vec::new(), Config::B32_CROCKFORD.check_symbol(true));
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(writer.get_ref(), b"D1JPRV3F");
+    assert_eq!(writer.finish().unwrap(), b"D1JPRV3FJ");
+
+    // configurations that can't be encoded in pieces are only encoded at the end
+    let mut writer = EncoderWriter::new(Vec::new(), Config::B58);
+    writer.write_all(b"hello").unwrap();
+    assert_eq!(writer.get_ref(), b"");
+    assert_eq!(writer.finish().unwrap(), b"Cn8eVZg");
+}
+
+#[test]
+fn test_io_encode_errors() {
+    // A writer that fails after writing `limit` bytes
+    struct LimitedWriter {
+        written: Vec<u8>,
+        limit: usize,
+    }
+
+    impl Write for LimitedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.limit - self.written.len());
+            if len == 0 {
+                return Err(io::ErrorKind::Other.into());
+            }
+            self.written.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = EncoderWriter::new(Vec::new(), Config::Z85);
+    writer.write_all(b"hello").unwrap();
+    let err = writer.finish().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        *err.into_inner().unwrap().downcast::<EncodeError>().unwrap(),
+        EncodeError::WrongInputLength(x) if x.length() == 5,
+    ));
+
+    // errors that writing more bytes can't fix are returned by `write`
+    let mut writer = EncoderWriter::new(Vec::new(), Config::bech32("a"));
+    writer.write_all(&[0; 40]).unwrap();
+    let err = writer.write(&[0; 40]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        *err.into_inner().unwrap().downcast::<EncodeError>().unwrap(),
+        EncodeError::WrongInputLength(x) if x.length() == 80,
+    ));
+    assert_eq!(writer.write(&[0; 10]).unwrap(), 10);
+    assert_eq!(
+        writer.finish().unwrap(),
+        Config::bech32("a")
+            .encode_to_string(&[0; 50])
+            .unwrap()
+            .as_bytes(),
+    );
+
+    let inner = LimitedWriter {
+        written: Vec::new(),
+        limit: 6,
+    };
+    let mut writer = EncoderWriter::new(inner, Config::HEX);
+    assert_eq!(writer.write(b"hello").unwrap(), 5);
+    assert_eq!(writer.get_ref().written, b"68656C");
+
+    // the bytes that couldn't be written are kept until the wrapped writer accepts them
+    assert!(writer.write(b"!").is_err());
+    assert!(writer.flush().is_err());
+}
+
+#[test]
+fn test_io_error_display() {
+    let err = Config::B64.decode_to_vec(b"aGV!").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid byte (33_u8, the '!' character) for the base-64(standard) encoding at offset 3",
+    );
+
+    let err = Config::B64.decode_to_vec(b"AAAAA").unwrap_err();
+    assert_eq!(err.to_string(), "invalid input length for base-64: 5");
+
+    let err = io::Error::from(err);
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "invalid input length for base-64: 5");
+}
@@ -0,0 +1,195 @@
+use crate::{
+    decode_to_slice, encode_to_slice, BitOrder, Config, DecodeError, Encoding, LineEnding,
+    PaddingMode,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 3 } else { 30 };
+
+const CONFIGS: &[Config] = &[
+    Config::B64,
+    Config::B64_URL_SAFE.end_padding(false),
+    Config::B64.bit_order(BitOrder::LsbFirst),
+    Config::B64.line_wrap(5, LineEnding::CrLf),
+    Config::B32,
+    Config::B32_HEX.end_padding(false),
+    Config::B32_CROCKFORD.group(4, b'-'),
+    Config::B32.group(3, b' ').line_wrap(7, LineEnding::Lf),
+    Config::HEX,
+    Config::HEX.group(1, b':').line_wrap(1, LineEnding::CrLf),
+    Config::HEX_LOWER.bit_order(BitOrder::LsbFirst),
+    // the configurations that `Encoder` and `Decoder` don't support
+    Config::B32_CROCKFORD.check_symbol(true).group(4, b'-'),
+    Config::HEX.hex_byte_prefix("0x").hex_byte_separator(" "),
+    Config::B58,
+    Config::B58_FLICKR.group(3, b' '),
+    Config::B45,
+    Config::ASCII85.delimiters(true),
+    Config::Z85,
+    Config::bech32("a"),
+];
+
+#[test]
+fn test_iter_matches_slice() {
+    let mut rng = SmallRng::seed_from_u64(8340019283746550127);
+
+    for &cfg in CONFIGS {
+        for in_length in 0..=40 {
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 40];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+
+                // Z85 input must be a multiple of 4 bytes long
+                let mut expected = [0u8; 256];
+                let Ok(len) = encode_to_slice(input, &mut expected, cfg) else {
+                    let res = std::panic::catch_unwind(|| cfg.encode_iter(input));
+                    assert!(res.is_err(), "{:?}", cfg);
+                    continue;
+                };
+                let expected = &expected[..len];
+
+                let iter = cfg.encode_iter(input);
+                let (lower, upper) = iter.size_hint();
+                assert!(lower <= len && len <= upper.unwrap());
+                if !matches!(cfg.encoding, Encoding::Base58(_)) {
+                    assert_eq!(lower, len);
+                }
+                assert!(iter.eq(expected.iter().copied()), "{:?}", cfg);
+
+                let mut chars = cfg.encode_char_iter(input);
+                for (i, &b) in expected.iter().enumerate() {
+                    assert_eq!(chars.next(), Some(char::from(b)));
+
+                    let remaining = len - i - 1;
+                    assert_eq!(chars.size_hint(), (remaining, Some(remaining)));
+                }
+                assert_eq!(chars.next(), None);
+
+                let iter = cfg.decode_iter(expected);
+                let (lower, upper) = iter.size_hint();
+                assert!(lower <= in_length && in_length <= upper.unwrap());
+                assert!(iter.eq(input.iter().map(|&b| Ok(b))), "{:?}", cfg);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_iter_decode_errors() {
+    for (cfg, input) in [
+        (Config::B64, &b"aGVsbG8gd29ybGQ!"[..]),
+        (Config::B64, b"Zg==Zg=="),
+        (Config::B64, b"aGVsbG8gd29ybGQhIQC"),
+        (Config::B64, b"aGVsbG8gd29ybGQhIR=="),
+        (Config::B64, b"AAAAA"),
+        (
+            Config::B64.padding_mode(PaddingMode::Required),
+            b"aGVsbG8gd29ybGQhIQ",
+        ),
+        (Config::B64.padding_mode(PaddingMode::Forbidden), b"Zg=="),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSA!==="),
+        (Config::B32, b"NBSWY3DPFQQHO33SNRSAX"),
+        (Config::HEX, b"0123456789ABCDEF!0"),
+        (Config::HEX, b"0123456789ABCDEF0"),
+        (Config::HEX.strict_case(true), b"0123456789abcdef"),
+        (Config::HEX, b"R"),
+        (Config::B64.padding_mode(PaddingMode::Required), b"sNMpX=G"),
+        (Config::B64, b"!cLAa"),
+    ] {
+        let mut out = [0u8; 20];
+        let expected = decode_to_slice(input, &mut out, cfg).unwrap_err();
+
+        let mut iter = cfg.decode_iter(input);
+        let err = iter.by_ref().find_map(Result::err);
+        assert_eq!(err, Some(expected), "{:?}", input);
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn test_iter_decode_random_errors() {
+    let mut rng = SmallRng::seed_from_u64(2650781903542281906);
+
+    let configs = CONFIGS.iter().copied().chain([
+        Config::B64.padding_mode(PaddingMode::Required),
+        Config::B64.padding_mode(PaddingMode::Forbidden),
+        Config::B32.padding_mode(PaddingMode::Required),
+        Config::HEX.strict_case(true),
+    ]);
+
+    for cfg in configs {
+        for in_length in 0..=20 {
+            for _ in 0..GEN_ITERS {
+                let mut encoded = [0u8; 128];
+                let len = encode_to_slice(&[0xA5; 10][..in_length / 2], &mut encoded, cfg);
+                let len = len.unwrap_or(0);
+
+                // mostly valid characters, with some of them replaced
+                let mut input = [0u8; 40];
+                let input = &mut input[..in_length];
+                for (i, b) in input.iter_mut().enumerate() {
+                    *b = match rng.gen_range(0..8) {
+                        0 => b'=',
+                        1 => rng.gen(),
+                        _ => encoded[i % len.max(1)],
+                    };
+                }
+
+                let mut out = [0u8; 128];
+                let expected = decode_to_slice(input, &mut out, cfg);
+
+                let mut iter = cfg.decode_iter(input);
+                match expected {
+                    Ok(len) => assert!(
+                        iter.eq(out[..len].iter().map(|&b| Ok(b))),
+                        "{:?} {:?}",
+                        cfg,
+                        input,
+                    ),
+                    Err(expected) => {
+                        let err = iter.by_ref().find_map(Result::err);
+                        assert_eq!(err, Some(expected), "{:?} {:?}", cfg, input);
+                        assert_eq!(iter.next(), None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_iter_excess_bits() {
+    let mut iter = Config::B32.decode_iter(b"MZ======");
+    assert_eq!(iter.next(), Some(Ok(b'f')));
+    assert!(matches!(iter.next(), Some(Err(DecodeError::ExcessBits(_)))));
+    assert_eq!(iter.next(), None);
+
+    let cfg = Config::B32.allow_excess_bits(true);
+    assert!(cfg.decode_iter(b"MZ======").eq([Ok(b'f')]));
+}
+
+#[test]
+#[should_panic]
+fn test_iter_invalid_encode_input_panics() {
+    // Z85 requires the input length to be a multiple of 4
+    let _ = Config::Z85.encode_iter(b"hello");
+}
+
+#[test]
+#[cfg(not(feature = "alloc"))]
+fn test_iter_decode_whole_buffer_limit() {
+    let mut encoded = [0u8; 1024];
+    let len = encode_to_slice(&[1; 600], &mut encoded, Config::B58).unwrap();
+
+    let mut iter = Config::B58.decode_iter(&encoded[..len]);
+    let err = iter.next().unwrap().unwrap_err();
+    assert!(
+        matches!(err, DecodeError::WrongOutputLength(_)),
+        "{:?}",
+        err
+    );
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,121 @@
+use crate::{
+    decode, decoded_len, encode, encoded_len, test_utils::AsBytes, Config, DecodeError, LineEnding,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+// inserts `ending` after every `width` characters of `encoded`, except at the end.
+fn wrap<'a>(encoded: &[u8], width: usize, ending: &[u8], buffer: &'a mut [u8]) -> &'a [u8] {
+    let mut len = 0;
+    for (i, &b) in encoded.iter().enumerate() {
+        if i != 0 && i % width == 0 {
+            buffer[len..len + ending.len()].copy_from_slice(ending);
+            len += ending.len();
+        }
+        buffer[len] = b;
+        len += 1;
+    }
+    &buffer[..len]
+}
+
+#[test]
+fn test_line_wrap_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=12 {
+        for cfg in [
+            Config::B64,
+            Config::B64.end_padding(false),
+            Config::B32,
+            Config::B32_CROCKFORD.check_symbol(true),
+            Config::HEX,
+        ] {
+            for (ending, ending_bytes) in
+                [(LineEnding::Lf, &b"\n"[..]), (LineEnding::CrLf, b"\r\n")]
+            {
+                let width = rng.gen_range(1..=10);
+                let wrapped_cfg = cfg.line_wrap(width, ending);
+
+                for _ in 0..GEN_ITERS {
+                    let mut input = [0u8; 12];
+                    let input = &mut input[..in_length];
+                    rng.fill(&mut *input);
+
+                    let unwrapped_len = encoded_len(in_length, cfg);
+                    let mut buffer = [0u8; 80];
+                    let unwrapped =
+                        call_with_len!(buffer, unwrapped_len, encode(input, cfg)).unwrap();
+
+                    let mut expected = [0u8; 200];
+                    let expected = wrap(unwrapped, width, ending_bytes, &mut expected);
+
+                    let enc_len = encoded_len(in_length, wrapped_cfg);
+                    assert_eq!(enc_len, expected.len());
+
+                    let mut buffer = [0u8; 80];
+                    let encoded =
+                        call_with_len!(buffer, enc_len, encode(input, wrapped_cfg)).unwrap();
+                    assert_eq!(encoded, expected, "\nwidth: {}", width);
+
+                    assert_eq!(decoded_len(encoded, wrapped_cfg), in_length);
+
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(encoded, wrapped_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+
+                    // the unwrapped string is also accepted
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(unwrapped, wrapped_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_line_wrap_mime() {
+    const CFG: Config = Config::B64.line_wrap(76, LineEnding::CrLf);
+    const INPUT: &[u8; 120] = &[0xAB; 120];
+
+    const ENCODED: &str = crate::encode_as_str!(INPUT, CFG);
+    assert_eq!(ENCODED.len(), 160 + 4);
+
+    let mut lines = ENCODED.split("\r\n");
+    assert_eq!(lines.next().unwrap().len(), 76);
+    assert_eq!(lines.next().unwrap().len(), 76);
+    assert_eq!(lines.next().unwrap(), "q6urq6ur");
+    assert_eq!(lines.next(), None);
+
+    const DECODED: &[u8] = crate::decode!(ENCODED, CFG);
+    assert_eq!(DECODED, INPUT);
+
+    // lines that end exactly at the end of the string have no line ending
+    const EXACT: &str = crate::encode_as_str!(&[0u8; 6], Config::B64.line_wrap(4, LineEnding::Lf));
+    assert_eq!(EXACT, "AAAA\nAAAA");
+}
+
+#[test]
+fn test_line_wrap_ignored() {
+    let cfg = Config::B58.line_wrap(2, LineEnding::Lf);
+    assert_eq!(encoded_len(4, cfg), encoded_len(4, Config::B58));
+    assert_eq!(encode::<4>(b"abc", cfg).unwrap(), "ZiCa");
+
+    let err = decode::<3>(b"Zi\nCa", cfg).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 2),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_line_wrap_zero_width_panics() {
+    let _ = Config::B64.line_wrap(0, LineEnding::Lf);
+}
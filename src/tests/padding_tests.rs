@@ -0,0 +1,246 @@
+use crate::{decode, decoded_len, encode, encoded_len, test_utils::AsBytes, Config, DecodeError};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+#[test]
+fn test_padding_char_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for in_length in 0..=30 {
+        for cfg in [
+            Config::B64,
+            Config::B64_URL_SAFE,
+            Config::B32,
+            Config::B32_HEX,
+        ] {
+            for padding in [b'.', b'~', b'='] {
+                let custom_cfg = cfg.padding_char(padding);
+
+                for _ in 0..GEN_ITERS {
+                    let mut input = [0u8; 30];
+                    let input = &mut input[..in_length];
+                    rng.fill(&mut *input);
+
+                    let enc_len = encoded_len(in_length, custom_cfg);
+                    assert_eq!(encoded_len(in_length, cfg), enc_len);
+
+                    let mut buffer = [0u8; 80];
+                    let standard = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                    let mut expected = [0u8; 80];
+                    let expected = &mut expected[..enc_len];
+                    for (e, &b) in expected.iter_mut().zip(standard) {
+                        *e = if b == b'=' { padding } else { b };
+                    }
+
+                    let mut buffer = [0u8; 80];
+                    let encoded =
+                        call_with_len!(buffer, enc_len, encode(input, custom_cfg)).unwrap();
+                    assert_eq!(encoded, &*expected);
+
+                    assert_eq!(decoded_len(encoded, custom_cfg), in_length);
+
+                    let mut buffer = [0u8; 80];
+                    let decoded =
+                        call_with_len!(buffer, in_length, decode(encoded, custom_cfg)).unwrap();
+                    assert_eq!(decoded, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_padding_char_decode_errors() {
+    // `=` isn't padding when another padding character is used
+    let cfg = Config::B64.padding_char(b'.');
+    let err = decode::<6>(b"UnVzdA==", cfg).unwrap_err();
+    assert!(
+        matches!(&err, DecodeError::InvalidByte(x) if x.index() == 6 && x.byte() == b'='),
+        "{:?}",
+        err
+    );
+
+    // encodings without padding ignore the padding character
+    assert_eq!(
+        encode::<6>(b"Rus", Config::HEX.padding_char(b'0')).unwrap(),
+        "527573"
+    );
+}
+
+#[test]
+fn test_padding_char_macros() {
+    const CFG: Config = Config::B32.padding_char(b'~');
+
+    const ENCODED: &str = crate::encode_as_str!(b"hi", CFG);
+    assert_eq!(ENCODED, "NBUQ~~~~");
+
+    const DECODED: &[u8] = crate::decode!(ENCODED, CFG);
+    assert_eq!(DECODED, b"hi");
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_in_alphabet_panics() {
+    let _ = Config::B64.padding_char(b'+');
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_in_custom_alphabet_panics() {
+    let _ = Config::custom_b32(b"0123456789abcdefghjkmnpqrtuvwxyz").padding_char(b'z');
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_non_ascii_panics() {
+    let _ = Config::B32.padding_char(0x80);
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_group_separator_panics() {
+    let _ = Config::B64.group(4, b'.').padding_char(b'.');
+}
+
+#[test]
+#[should_panic]
+fn test_padding_char_line_ending_panics() {
+    let _ = Config::B64
+        .line_wrap(4, crate::LineEnding::CrLf)
+        .padding_char(b'\r');
+}
+
+#[test]
+#[should_panic]
+fn test_line_ending_padding_char_panics() {
+    let _ = Config::B64
+        .padding_char(b'\n')
+        .line_wrap(4, crate::LineEnding::Lf);
+}
+
+#[test]
+fn test_padding_char_unused_by_encoding() {
+    // Crockford isn't padded, so its padding character doesn't conflict with skipped bytes
+    let cfg = Config::B32_CROCKFORD.padding_char(b'.').group(4, b'.');
+    assert_eq!(encode::<9>(b"hello", cfg).unwrap(), "D1JP.RV3F");
+}
+
+#[test]
+fn test_padding_mode() {
+    use crate::PaddingMode;
+
+    // (config, unpadded, amount of padding the encoder emits)
+    let cases: [(Config, &[u8], usize); 6] = [
+        (Config::B64, b"Zg", 2),
+        (Config::B64, b"Zm8", 1),
+        (Config::B64, b"Zm9v", 0),
+        (Config::B32, b"MY", 6),
+        (Config::B32, b"MZXQ", 4),
+        (Config::B32.padding_char(b'.'), b"MZXW6", 3),
+    ];
+
+    for (cfg, unpadded, canonical) in cases {
+        let decoded_len = decoded_len(unpadded, cfg);
+
+        for padding in 0..=8 {
+            let mut input = [0u8; 16];
+            input[..unpadded.len()].copy_from_slice(unpadded);
+            input[unpadded.len()..][..padding].fill(cfg.padding);
+            let input = &input[..unpadded.len() + padding];
+
+            for (mode, expected) in [
+                (PaddingMode::Indifferent, None),
+                (PaddingMode::Required, Some(canonical)),
+                (PaddingMode::Forbidden, Some(0)),
+            ] {
+                for end_padding in [false, true] {
+                    let cfg = cfg.padding_mode(mode).end_padding(end_padding);
+
+                    assert_eq!(crate::decoded_len(input, cfg), decoded_len);
+
+                    let mut buffer = [0u8; 8];
+                    let res = call_with_len!(buffer, decoded_len, decode(input, cfg));
+
+                    match expected {
+                        Some(expected) if expected != padding => {
+                            let err = res.unwrap_err();
+                            assert!(
+                                matches!(
+                                    &err,
+                                    DecodeError::InvalidPadding(x)
+                                    if x.expected() == expected &&
+                                        x.found() == padding &&
+                                        x.encoding() == cfg.encoding
+                                ),
+                                "{:?} {:?}",
+                                err,
+                                input,
+                            );
+                        }
+                        _ => {
+                            res.unwrap();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_padding_without_end_padding() {
+    use crate::{PaddingMode, WrongOutputLength};
+
+    // padding isn't decoded unless `end_padding` is enabled or a padding mode is set
+    let cfg = Config::B64.end_padding(false);
+    assert_eq!(
+        decode::<1>(b"Zg==", cfg),
+        Err(DecodeError::WrongOutputLength(WrongOutputLength {
+            expected: 3,
+            found: 1
+        }))
+    );
+    assert!(matches!(
+        decode::<3>(b"Zg==", cfg),
+        Err(DecodeError::InvalidByte(x)) if x.index() == 2 && x.byte() == b'='
+    ));
+    assert_eq!(decode::<1>(b"Zg", cfg).unwrap(), *b"f");
+
+    let cfg = cfg.padding_mode(PaddingMode::Indifferent);
+    assert_eq!(decode::<1>(b"Zg==", cfg).unwrap(), *b"f");
+    assert_eq!(decode::<1>(b"Zg", cfg).unwrap(), *b"f");
+
+    let cfg = Config::B32.end_padding(false);
+    assert!(matches!(
+        decode::<5>(b"MZXW6===", cfg),
+        Err(DecodeError::InvalidByte(x)) if x.index() == 5
+    ));
+    let cfg = cfg.padding_mode(PaddingMode::Required);
+    assert_eq!(decode::<3>(b"MZXW6===", cfg).unwrap(), *b"foo");
+}
+
+#[test]
+fn test_padding_mode_ignored() {
+    use crate::PaddingMode;
+
+    // Crockford has no padding, `=` is one of its check symbols
+    let cfg = Config::B32_CROCKFORD
+        .check_symbol(true)
+        .padding_mode(PaddingMode::Forbidden);
+    assert_eq!(decode::<5>(b"D1JPRV3FJ", cfg).unwrap(), *b"hello");
+
+    for cfg in [Config::HEX, Config::B58, Config::B45, Config::ASCII85] {
+        let cfg = cfg.padding_mode(PaddingMode::Required);
+        let input = [0xFF; 8];
+
+        let mut buffer = [0u8; 16];
+        let enc_len = crate::encoded_len_of(&input, cfg);
+        let encoded = call_with_len!(buffer, enc_len, encode(&input, cfg)).unwrap();
+
+        assert_eq!(decode::<8>(encoded, cfg).unwrap(), input);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::sha256::{double_sha256, sha256};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use sha2::{Digest, Sha256};
+
+#[test]
+fn test_sha256_known_hashes() {
+    const EMPTY: [u8; 32] = sha256(b"");
+    assert_eq!(
+        EMPTY[..],
+        *b"\xe3\xb0\xc4\x42\x98\xfc\x1c\x14\x9a\xfb\xf4\xc8\x99\x6f\xb9\x24\
+           \x27\xae\x41\xe4\x64\x9b\x93\x4c\xa4\x95\x99\x1b\x78\x52\xb8\x55"
+    );
+
+    assert_eq!(
+        sha256(b"abc")[..],
+        *b"\xba\x78\x16\xbf\x8f\x01\xcf\xea\x41\x41\x40\xde\x5d\xae\x22\x23\
+           \xb0\x03\x61\xa3\x96\x17\x7a\x9c\xb4\x10\xff\x61\xf2\x00\x15\xad"
+    );
+}
+
+#[test]
+fn test_sha256_against_sha2() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    let mut input = [0u8; 300];
+    rng.fill(&mut input[..]);
+
+    for len in 0..input.len() {
+        let input = &input[..len];
+
+        let expected = Sha256::digest(input);
+        assert_eq!(sha256(input)[..], expected[..], "len: {}", len);
+
+        let expected = Sha256::digest(&expected);
+        assert_eq!(double_sha256(input)[..], expected[..], "len: {}", len);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    decode, decode_to_slice, decoded_len, encode, encode_to_slice, encoded_len_of,
+    test_utils::AsBytes, Config, DecodeError, EncodeError, LineEnding, WrongOutputLength,
+};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const GEN_ITERS: usize = if cfg!(miri) { 10 } else { 100 };
+
+fn configs() -> [Config; 16] {
+    [
+        Config::B64,
+        Config::B64_URL_SAFE.end_padding(false),
+        Config::B32,
+        Config::B32_CROCKFORD.check_symbol(true),
+        Config::HEX,
+        Config::HEX_LOWER
+            .hex_byte_prefix("0x")
+            .hex_byte_separator(", "),
+        Config::B58,
+        Config::B58.checksum(true),
+        Config::B45,
+        Config::ASCII85,
+        Config::ASCII85.delimiters(true),
+        Config::Z85,
+        Config::bech32("bc"),
+        Config::bech32m("a"),
+        Config::B64.line_wrap(4, LineEnding::CrLf),
+        Config::B58.group(3, b' '),
+    ]
+}
+
+#[test]
+fn test_slice_encode_decode() {
+    let mut rng = SmallRng::seed_from_u64(6249204433781597762);
+
+    for cfg in configs() {
+        for in_length in 0..=12 {
+            if matches!(
+                cfg.encoding,
+                crate::Encoding::Base85(crate::B85CharSet::Z85)
+            ) && in_length % 4 != 0
+            {
+                continue;
+            }
+
+            for _ in 0..GEN_ITERS {
+                let mut input = [0u8; 16];
+                let input = &mut input[..in_length];
+                rng.fill(&mut *input);
+                if rng.gen_bool(0.2) {
+                    input[..in_length / 2].fill(0);
+                }
+
+                let enc_len = encoded_len_of(input, cfg);
+                let mut buffer = [0u8; 80];
+                let expected = call_with_len!(buffer, enc_len, encode(input, cfg)).unwrap();
+
+                // the bytes after the encoded string are left untouched
+                let mut out = [0xFFu8; 100];
+                let len = encode_to_slice(input, &mut out, cfg).unwrap();
+                assert_eq!(&out[..len], expected, "{:?}", cfg.encoding);
+                assert!(out[len..].iter().all(|&b| b == 0xFF));
+
+                let mut exact = [0xFFu8; 100];
+                let exact = &mut exact[..enc_len];
+                assert_eq!(encode_to_slice(input, exact, cfg), Ok(enc_len));
+                assert_eq!(exact, expected);
+
+                let mut decoded = [0xFFu8; 40];
+                let len = decode_to_slice(expected, &mut decoded, cfg).unwrap();
+                assert_eq!(len, decoded_len(expected, cfg));
+                assert_eq!(&decoded[..len], input, "{:?}", cfg.encoding);
+                assert!(decoded[len..].iter().all(|&b| b == 0xFF));
+
+                let mut exact = [0xFFu8; 40];
+                let exact = &mut exact[..in_length];
+                assert_eq!(decode_to_slice(expected, exact, cfg), Ok(in_length));
+                assert_eq!(exact, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_slice_too_short() {
+    for cfg in configs() {
+        let input: &[u8] = if cfg.encoding == crate::Encoding::Base85(crate::B85CharSet::Z85) {
+            b"\x00\x01\x02\x03\xFF\xFE\xFD\xFC"
+        } else {
+            b"\x00\x01\x02\xFF\xFE"
+        };
+
+        let enc_len = encoded_len_of(input, cfg);
+        let mut encoded = [0u8; 80];
+        let len = encode_to_slice(input, &mut encoded, cfg).unwrap();
+        let encoded = &encoded[..len];
+
+        for out_len in 0..enc_len {
+            let mut out = [0u8; 80];
+            assert_eq!(
+                encode_to_slice(input, &mut out[..out_len], cfg),
+                Err(EncodeError::WrongOutputLength(WrongOutputLength {
+                    expected: enc_len,
+                    found: out_len,
+                })),
+                "{:?}",
+                cfg.encoding,
+            );
+        }
+
+        for out_len in 0..input.len() {
+            let mut out = [0u8; 80];
+            assert_eq!(
+                decode_to_slice(encoded, &mut out[..out_len], cfg),
+                Err(DecodeError::WrongOutputLength(WrongOutputLength {
+                    expected: input.len(),
+                    found: out_len,
+                })),
+                "{:?}",
+                cfg.encoding,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_slice_errors() {
+    // the other errors are the same as those of `decode`
+    for (cfg, input) in [
+        (Config::B64, &b"Zm9!"[..]),
+        (Config::B32, b"MZX"),
+        (Config::HEX, b"F0F"),
+        (Config::B58.checksum(true), b"3vQB7B6MrGQZaxCvV"),
+        (Config::B45, b"GGW"),
+        (Config::ASCII85, b"s8W-\""),
+        (Config::bech32("a"), b"a12uel5m"),
+    ] {
+        let mut out = [0u8; 40];
+        let err = decode_to_slice(input, &mut out, cfg).unwrap_err();
+
+        let mut buffer = [0u8; 40];
+        let len = decoded_len(input, cfg);
+        let expected = call_with_len!(buffer, len, decode(input, cfg)).unwrap_err();
+
+        assert_eq!(err, expected);
+    }
+}
+
+#[test]
+fn test_slice_const() {
+    const ENCODED: ([u8; 16], usize) = {
+        let mut out = [0u8; 16];
+        let len = EncodeError::unwrap(encode_to_slice(b"hello", &mut out, Config::B64));
+        (out, len)
+    };
+    assert_eq!(&ENCODED.0[..ENCODED.1], b"aGVsbG8=");
+
+    const DECODED: ([u8; 16], usize) = {
+        let mut out = [0u8; 16];
+        let len = DecodeError::unwrap(decode_to_slice(b"StV1DL6CwTryKyV", &mut out, Config::B58));
+        (out, len)
+    };
+    assert_eq!(&DECODED.0[..DECODED.1], b"hello world");
+}
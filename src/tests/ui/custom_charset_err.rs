@@ -0,0 +1,9 @@
+use const_base::Config;
+
+const CFG: Config = Config::custom_b64(
+    b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz012345678.",
+);
+
+fn main() {
+    let _ = CFG;
+}
@@ -5,4 +5,5 @@ fn main(){
     decode!(b"A===", Config::B64.end_padding(true));
     decode!(b"AAAAA", Config::B64);
     decode!(b"AAA\x00AA", Config::B64);
+    decode!(b"28-*", Config::B32_CROCKFORD.check_symbol(true));
 }
\ No newline at end of file